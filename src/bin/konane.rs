@@ -1,10 +1,200 @@
-use konane_engine::Board;
+use std::{
+    env, fs, process,
+    time::{Duration, Instant},
+};
+
+use konane_engine::{
+    divide_game, perft_game, BoardBuilder, Game, GameRecord, Phase, SearchLimits, Searcher, Square,
+    StopFlag, TranspositionTable, Turn,
+};
+
+const USAGE: &str =
+    "Usage: konane [play [--time <ms>] [--size <n>] [--hash <mb>] [--record <file>] \
+                     | perft <depth> [--size <n>] | replay <file>]";
 
 fn main() {
-    let board = Board::default();
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    match args.first().map(String::as_str) {
+        None => show_start(),
+        Some("play") => play(&args[1..]),
+        Some("perft") => perft(&args[1..]),
+        Some("replay") => replay(&args[1..]),
+        Some(_) => fail(USAGE),
+    }
+}
+
+/// Print the standard starting position and the choices open to Black.
+fn show_start() {
+    let game = Game::default();
+
+    println!("{}", game.board());
+    println!("{:?} to move", game.side_to_move());
+
+    match game.phase() {
+        Phase::Opening => {
+            let removals = game
+                .legal_removals()
+                .iter()
+                .map(Square::to_string)
+                .collect::<Vec<_>>();
+            println!("Legal removals: {}", removals.join(" "));
+        }
+        Phase::Jumping => println!("Legal moves: {:?}", game.legal_moves()),
+    }
+
+    if let Some(winner) = game.result() {
+        println!("Game over, {:?} wins", winner);
+    }
+}
+
+/// Have the engine play a whole game against itself, thinking for a fixed time on every move.
+/// Opening removals are taken from the first legal choice. The game can be saved as a record.
+fn play(args: &[String]) {
+    let mut time = Duration::from_millis(1000);
+    let mut size = 6;
+    let mut hash = 16;
+    let mut record = None;
+
+    let mut args = args.iter();
+    while let Some(flag) = args.next() {
+        let value = args.next();
+        let number = value.and_then(|value| value.parse::<u64>().ok());
+
+        match (flag.as_str(), number) {
+            ("--time", Some(ms)) => time = Duration::from_millis(ms),
+            ("--size", Some(n)) => size = n as usize,
+            ("--hash", Some(mb)) => hash = mb as usize,
+            ("--record", _) if value.is_some() => record = value,
+            _ => fail(USAGE),
+        }
+    }
+
+    let board = BoardBuilder::new()
+        .size(size, size)
+        .build()
+        .unwrap_or_else(|error| fail(&error.to_string()));
+    let mut game = Game::opening(board);
+    let limits = SearchLimits::new().time(time);
+    let mut searcher = Searcher::new().with_table(TranspositionTable::with_megabytes(hash));
+
+    while game.result().is_none() {
+        let side = game.side_to_move();
+
+        match game.phase() {
+            Phase::Opening => {
+                let square = game.legal_removals()[0];
+                game.remove(square).expect("listed removals are legal");
+
+                println!("{:?} removes {}", side, square);
+            }
+            Phase::Jumping => {
+                let result = searcher
+                    .iterative_deepening(game.board(), side, &limits, &StopFlag::new())
+                    .expect("the game isn't over, so there is a legal move");
+                game.play(result.best_move)
+                    .expect("the search only returns legal moves");
+
+                println!(
+                    "{:?} plays {} (depth {}, score {}, {} nodes)",
+                    side, result.best_move, result.depth, result.score, result.nodes
+                );
+            }
+        }
+
+        println!("{}", game.board());
+    }
+
+    if let Some(winner) = game.result() {
+        println!("Game over, {:?} wins after {} turns", winner, game.ply());
+    }
+
+    if let Some(path) = record {
+        let mut record = GameRecord::from_game(&game);
+        for side in ["Black", "White"] {
+            record
+                .set_tag(side, "konane")
+                .expect("the player's name is a valid tag");
+        }
+
+        fs::write(path, record.to_string()).unwrap_or_else(|error| fail(&error.to_string()));
+    }
+}
+
+/// Read a game record, replaying every turn, and print how the game ended up.
+fn replay(args: &[String]) {
+    let [path] = args else {
+        fail(USAGE);
+    };
+
+    let text = fs::read_to_string(path).unwrap_or_else(|error| fail(&error.to_string()));
+    let record = text
+        .parse::<GameRecord>()
+        .unwrap_or_else(|error| fail(&format!("{}: {}", path, error)));
+    let game = record
+        .to_game()
+        .expect("the record was replayed as it was read");
+
+    for (name, value) in record.tags() {
+        println!("{}: {}", name, value);
+    }
+    println!();
+    println!("{}", game.board());
+
+    match game.result() {
+        Some(winner) => println!("Game over, {:?} wins after {} turns", winner, game.ply()),
+        None => println!(
+            "{:?} to move after {} turns",
+            game.side_to_move(),
+            game.ply()
+        ),
+    }
+}
+
+/// Count every line of play from the standard opening to the given depth, removals included,
+/// broken down by the first turn.
+fn perft(args: &[String]) {
+    let Some(depth) = args.first().and_then(|depth| depth.parse::<usize>().ok()) else {
+        fail(USAGE);
+    };
+    let mut size = 6;
+
+    let mut args = args[1..].iter();
+    while let Some(flag) = args.next() {
+        let value = args.next().and_then(|value| value.parse::<usize>().ok());
+
+        match (flag.as_str(), value) {
+            ("--size", Some(n)) => size = n,
+            _ => fail(USAGE),
+        }
+    }
+
+    let board = BoardBuilder::new()
+        .size(size, size)
+        .build()
+        .unwrap_or_else(|error| fail(&error.to_string()));
+    let game = Game::opening(board);
+
+    let start = Instant::now();
+    let counts = divide_game(&game, depth);
+    for (turn, count) in &counts {
+        match turn {
+            Turn::Removal(square) => println!("remove {}: {}", square, count),
+            Turn::Jump(jump) => println!("{}: {}", jump, count),
+        }
+    }
+
+    // With nothing to break down, such as at depth zero, count the position itself
+    let total = if counts.is_empty() {
+        perft_game(&game, depth)
+    } else {
+        counts.iter().map(|(_, count)| count).sum::<u64>()
+    };
+    println!();
+    println!("{} lines in {:?}", total, start.elapsed());
+}
 
-    println!(
-        "Possible moves from (1, 2): {:?}",
-        board.possible_moves(1, 2).unwrap()
-    );
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
 }
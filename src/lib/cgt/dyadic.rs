@@ -0,0 +1,189 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Neg},
+};
+
+/// A fraction whose denominator is a power of two. These are the only numbers that turn up as
+/// the values of finite games.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::Dyadic;
+///
+/// let three_quarters = Dyadic::new(6, 3);
+///
+/// assert_eq!(three_quarters.numerator(), 3);
+/// assert_eq!(three_quarters.denominator(), 4);
+/// assert_eq!(three_quarters.to_string(), "3/4");
+/// assert_eq!(three_quarters + Dyadic::new(1, 2), Dyadic::integer(1));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Dyadic {
+    numerator: i64,
+    exponent: u32,
+}
+
+impl Dyadic {
+    /// The number `numerator / 2^exponent`, in lowest terms.
+    pub fn new(numerator: i64, exponent: u32) -> Dyadic {
+        let mut dyadic = Dyadic {
+            numerator,
+            exponent,
+        };
+        while dyadic.exponent > 0 && dyadic.numerator % 2 == 0 {
+            dyadic.numerator /= 2;
+            dyadic.exponent -= 1;
+        }
+
+        dyadic
+    }
+
+    /// A whole number.
+    pub fn integer(value: i64) -> Dyadic {
+        Dyadic::new(value, 0)
+    }
+
+    pub fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i64 {
+        1 << self.exponent
+    }
+
+    /// Whether the number is a whole number.
+    pub fn is_integer(&self) -> bool {
+        self.exponent == 0
+    }
+
+    /// The largest whole number no greater than this one.
+    pub fn floor(&self) -> i64 {
+        self.numerator.div_euclid(self.denominator())
+    }
+
+    /// The smallest whole number no less than this one.
+    pub fn ceil(&self) -> i64 {
+        -(-self.numerator).div_euclid(self.denominator())
+    }
+
+    /// The simplest number strictly between `low` and `high`, where `None` means there's no
+    /// bound on that side. This is the value of a game whose options are all numbers, with
+    /// every left option less than every right option.
+    pub(crate) fn simplest_between(low: Option<Dyadic>, high: Option<Dyadic>) -> Dyadic {
+        // The whole numbers just inside each bound
+        let above_low = low.map(|low| low.floor() + 1);
+        let below_high = high.map(|high| high.ceil() - 1);
+
+        let integer = match (above_low, below_high) {
+            (low, high) if low.is_none_or(|low| low <= 0) && high.is_none_or(|high| high >= 0) => {
+                Some(0)
+            }
+            (Some(low), high) if low > 0 => high.is_none_or(|high| low <= high).then_some(low),
+            (low, Some(high)) => low.is_none_or(|low| low <= high).then_some(high),
+            _ => None,
+        };
+        if let Some(integer) = integer {
+            return Dyadic::integer(integer);
+        }
+
+        let (low, high) = (
+            low.expect("only bounded intervals can miss every integer"),
+            high.expect("only bounded intervals can miss every integer"),
+        );
+        (1..)
+            .map(|exponent| Dyadic::new(low.scaled_floor(exponent) + 1, exponent))
+            .find(|&candidate| candidate < high)
+            .expect("there's always a number between two different numbers")
+    }
+
+    /// The largest whole number no greater than this number times `2^exponent`.
+    fn scaled_floor(&self, exponent: u32) -> i64 {
+        if self.exponent >= exponent {
+            self.numerator.div_euclid(1 << (self.exponent - exponent))
+        } else {
+            self.numerator << (exponent - self.exponent)
+        }
+    }
+}
+
+impl PartialOrd for Dyadic {
+    fn partial_cmp(&self, other: &Dyadic) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Dyadic {
+    fn cmp(&self, other: &Dyadic) -> Ordering {
+        let exponent = self.exponent.max(other.exponent);
+        let scale = |dyadic: &Dyadic| (dyadic.numerator as i128) << (exponent - dyadic.exponent);
+
+        scale(self).cmp(&scale(other))
+    }
+}
+
+impl Add for Dyadic {
+    type Output = Dyadic;
+
+    fn add(self, rhs: Dyadic) -> Dyadic {
+        let exponent = self.exponent.max(rhs.exponent);
+
+        Dyadic::new(
+            self.scaled_floor(exponent) + rhs.scaled_floor(exponent),
+            exponent,
+        )
+    }
+}
+
+impl Neg for Dyadic {
+    type Output = Dyadic;
+
+    fn neg(self) -> Dyadic {
+        Dyadic::new(-self.numerator, self.exponent)
+    }
+}
+
+impl Display for Dyadic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dyadic;
+
+    #[test]
+    fn simplest_numbers() {
+        let simplest = |low: Option<(i64, u32)>, high: Option<(i64, u32)>| {
+            let to_dyadic = |(numerator, exponent)| Dyadic::new(numerator, exponent);
+            Dyadic::simplest_between(low.map(to_dyadic), high.map(to_dyadic)).to_string()
+        };
+
+        assert_eq!(simplest(None, None), "0");
+        assert_eq!(simplest(Some((0, 0)), None), "1");
+        assert_eq!(simplest(Some((5, 1)), None), "3");
+        assert_eq!(simplest(None, Some((-2, 0))), "-3");
+        assert_eq!(simplest(Some((-3, 0)), Some((7, 0))), "0");
+        assert_eq!(simplest(Some((0, 0)), Some((1, 0))), "1/2");
+        assert_eq!(simplest(Some((1, 1)), Some((1, 0))), "3/4");
+        assert_eq!(simplest(Some((3, 3)), Some((1, 1))), "7/16");
+        assert_eq!(simplest(Some((-1, 0)), Some((-5, 3))), "-3/4");
+        assert_eq!(simplest(Some((1, 0)), Some((3, 0))), "2");
+    }
+
+    #[test]
+    fn ordering_and_arithmetic() {
+        assert!(Dyadic::new(1, 1) < Dyadic::new(5, 3));
+        assert!(Dyadic::integer(-1) < Dyadic::new(-1, 1));
+        assert_eq!(-Dyadic::new(3, 2), Dyadic::new(-3, 2));
+        assert_eq!(Dyadic::new(-7, 2).floor(), -2);
+        assert_eq!(Dyadic::new(-7, 2).ceil(), -1);
+        assert_eq!(Dyadic::new(12, 4), Dyadic::new(3, 2));
+    }
+}
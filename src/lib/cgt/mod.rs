@@ -0,0 +1,7 @@
+mod dyadic;
+mod table;
+mod value;
+
+pub use dyadic::Dyadic;
+pub use table::{game_value, ValueTable};
+pub use value::GameValue;
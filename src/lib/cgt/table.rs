@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use super::GameValue;
+use crate::{Board, Piece};
+
+/// Work out the canonical value of a position, with Black playing Left and White playing Right.
+/// Every position reachable from it is valued along the way, so this is only practical for
+/// endgames and small boards. Positions that fall apart into [regions](Board::regions) are
+/// valued one region at a time.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{game_value, Board, Piece};
+///
+/// // Either player can jump the other, after which nobody can move
+/// let mut board = Board::with_size(4, 1).unwrap();
+/// let _ = board.set_piece((0, 1), Piece::BLACK);
+/// let _ = board.set_piece((0, 2), Piece::WHITE);
+///
+/// assert_eq!(game_value(&board).to_string(), "*");
+/// ```
+pub fn game_value(board: &Board) -> GameValue {
+    ValueTable::new().value(board)
+}
+
+/// Works out canonical values like [`game_value`], remembering the value of every position it
+/// comes across so that later positions from the same game are valued more quickly.
+#[derive(Clone, Debug, Default)]
+pub struct ValueTable {
+    /// The value of each position, by its Zobrist key, along with the board itself so that two
+    /// positions sharing a key can't be mistaken for each other. Values don't depend on who is
+    /// to move.
+    values: HashMap<u64, (Board, GameValue)>,
+}
+
+impl ValueTable {
+    /// Create a table that hasn't valued anything yet.
+    pub fn new() -> ValueTable {
+        ValueTable::default()
+    }
+
+    /// The canonical value of `board`.
+    pub fn value(&mut self, board: &Board) -> GameValue {
+        self.value_of(&mut board.clone())
+    }
+
+    /// The number of positions the table remembers.
+    pub fn positions(&self) -> usize {
+        self.values.len()
+    }
+
+    fn value_of(&mut self, board: &mut Board) -> GameValue {
+        let key = board.zobrist_key();
+        if let Some((_, value)) = self.values.get(&key).filter(|(valued, _)| valued == board) {
+            return value.clone();
+        }
+
+        // A position that splits into regions is the sum of their values, and each region is
+        // much quicker to value on its own
+        let regions = board.regions();
+        let value = if regions.len() > 1 {
+            regions
+                .into_iter()
+                .map(|mut region| self.value_of(&mut region))
+                .fold(GameValue::zero(), |sum, value| sum + value)
+        } else {
+            let left = self.options(board, Piece::BLACK);
+            let right = self.options(board, Piece::WHITE);
+            GameValue::new(left, right)
+        };
+
+        self.values.insert(key, (board.position(), value.clone()));
+        value
+    }
+
+    /// The values of the positions `side` can move to.
+    fn options(&mut self, board: &mut Board, side: Piece) -> Vec<GameValue> {
+        board
+            .legal_moves(side)
+            .into_iter()
+            .map(|jump| {
+                board
+                    .apply_move(jump)
+                    .expect("generated moves are always legal");
+                let value = self.value_of(board);
+                board.unmake_move().expect("the move was just applied");
+
+                value
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        game_value, Board, BoardBuilder, Game, GameValue, Outcome, Piece, Solver, ValueTable,
+    };
+
+    #[test]
+    fn single_jumps_are_integers() {
+        let mut board = Board::create_empty();
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((0, 1), Piece::WHITE);
+        assert_eq!(game_value(&board).to_string(), "1");
+
+        // Two jumps for White, which Black can't answer
+        let _ = board.set_piece((0, 5), Piece::WHITE);
+        let _ = board.set_piece((1, 5), Piece::BLACK);
+        let _ = board.set_piece((5, 0), Piece::WHITE);
+        let _ = board.set_piece((5, 1), Piece::BLACK);
+        assert_eq!(game_value(&board).to_string(), "-1");
+    }
+
+    #[test]
+    fn ignores_positions_that_only_share_a_key() {
+        let mut board = Board::create_empty();
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((0, 1), Piece::WHITE);
+
+        // Pretend some other position shares the board's key
+        let mut table = ValueTable::new();
+        let key = board.zobrist_key();
+        table
+            .values
+            .insert(key, (Board::default(), GameValue::zero()));
+
+        assert_eq!(table.value(&board).to_string(), "1");
+    }
+
+    #[test]
+    fn values_agree_with_the_solver() {
+        let mut game = Game::opening(BoardBuilder::new().size(4, 4).build().unwrap());
+        game.remove((0, 0)).unwrap();
+        game.remove((0, 1)).unwrap();
+
+        let mut table = ValueTable::new();
+        let mut solver = Solver::new();
+
+        // Check every position along a game, for both players moving first
+        while game.result().is_none() {
+            let value = table.value(game.board());
+
+            for side in [Piece::BLACK, Piece::WHITE] {
+                let winner = match solver.solve(game.board(), side).outcome {
+                    Outcome::Win => side,
+                    Outcome::Loss => side.opponent(),
+                };
+                assert_eq!(value.winner(side), winner, "{} in\n{}", value, game.board());
+            }
+
+            game.play(game.legal_moves()[0]).unwrap();
+        }
+
+        assert!(table.positions() > 1);
+    }
+
+    #[test]
+    fn regions_are_valued_separately() {
+        let mut board = Board::create_empty();
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((0, 1), Piece::WHITE);
+        let _ = board.set_piece((5, 3), Piece::BLACK);
+        let _ = board.set_piece((5, 4), Piece::WHITE);
+
+        let mut table = ValueTable::new();
+        let value = table.value(&board);
+        let sum = board
+            .regions()
+            .iter()
+            .map(game_value)
+            .fold(GameValue::zero(), |sum, value| sum + value);
+
+        // Black's lone jump in the corner is worth 1, and the pair in the middle is *
+        assert_eq!(value, sum);
+        assert_eq!(value.to_string(), "1*");
+    }
+}
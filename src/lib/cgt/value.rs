@@ -0,0 +1,511 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    ops::{Add, Neg},
+    rc::Rc,
+};
+
+use super::Dyadic;
+use crate::{splitmix::mix, Piece};
+
+/// The canonical form of a combinatorial game, with Black playing Left and White playing Right.
+///
+/// Every game has exactly one canonical form, the simplest game equal to it, so two values are
+/// equal exactly when their games are. Values are only partially ordered: a value greater than
+/// zero is a win for Black whoever moves first, a value less than zero is a win for White, zero
+/// is a win for whoever moves second, and a value confused with zero is a win for whoever moves
+/// first.
+///
+/// Values print as numbers (`3/4`), nimbers (`*`, `*2`), ups and downs (`↑`, `⇓*`), switches
+/// (`±1`), sums of a number with any of those (`1/2↑`), and `{left|right}` otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::GameValue;
+///
+/// let star = GameValue::nimber(1);
+/// let up = GameValue::new([GameValue::zero()], [star.clone()]);
+///
+/// assert_eq!(up.to_string(), "↑");
+/// assert_eq!((up.clone() + star.clone()).to_string(), "↑*");
+/// assert_eq!(star.clone() + star.clone(), GameValue::zero());
+///
+/// assert!(up > GameValue::zero());
+/// assert_eq!(star.partial_cmp(&GameValue::zero()), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct GameValue(Rc<Form>);
+
+#[derive(Debug)]
+struct Form {
+    left: Vec<GameValue>,
+    right: Vec<GameValue>,
+    /// Computed from the options' hashes when the form is built, so that deep values can be
+    /// hashed and told apart quickly.
+    hash: u64,
+}
+
+impl GameValue {
+    /// The value of the game with these options, simplified to canonical form.
+    pub fn new(
+        left: impl IntoIterator<Item = GameValue>,
+        right: impl IntoIterator<Item = GameValue>,
+    ) -> GameValue {
+        let mut left = left.into_iter().collect::<Vec<_>>();
+        let mut right = right.into_iter().collect::<Vec<_>>();
+
+        loop {
+            left = undominated(left, |a, b| a.leq(b));
+            right = undominated(right, |a, b| b.leq(a));
+            let game = GameValue::from_canonical_options(left.clone(), right.clone());
+
+            // A left option is reversible if Right can answer it with a position at least as
+            // good for Right as the whole game, in which case Left may as well move straight
+            // on to that position's left options. Likewise for Right.
+            let mut reversed = false;
+            let mut bypassed_left = vec![];
+            for option in left {
+                match option.right().iter().find(|reply| reply.leq(&game)) {
+                    Some(reply) => {
+                        bypassed_left.extend(reply.left().iter().cloned());
+                        reversed = true;
+                    }
+                    None => bypassed_left.push(option),
+                }
+            }
+
+            let mut bypassed_right = vec![];
+            for option in right {
+                match option.left().iter().find(|reply| game.leq(reply)) {
+                    Some(reply) => {
+                        bypassed_right.extend(reply.right().iter().cloned());
+                        reversed = true;
+                    }
+                    None => bypassed_right.push(option),
+                }
+            }
+
+            if !reversed {
+                return game;
+            }
+
+            left = bypassed_left;
+            right = bypassed_right;
+        }
+    }
+
+    /// The game where neither player can move, which the player to move loses.
+    pub fn zero() -> GameValue {
+        GameValue::from_canonical_options(vec![], vec![])
+    }
+
+    /// The canonical form of a number.
+    pub fn number(number: Dyadic) -> GameValue {
+        if number.is_integer() {
+            let value = number.numerator();
+            match value.cmp(&0) {
+                Ordering::Equal => GameValue::zero(),
+                Ordering::Greater => GameValue::from_canonical_options(
+                    vec![GameValue::number(Dyadic::integer(value - 1))],
+                    vec![],
+                ),
+                Ordering::Less => GameValue::from_canonical_options(
+                    vec![],
+                    vec![GameValue::number(Dyadic::integer(value + 1))],
+                ),
+            }
+        } else {
+            // The numbers either side with the next smaller denominator
+            let step = Dyadic::new(1, number.denominator().trailing_zeros());
+            GameValue::from_canonical_options(
+                vec![GameValue::number(number + -step)],
+                vec![GameValue::number(number + step)],
+            )
+        }
+    }
+
+    /// The nimber `*n`, where both players can move to any smaller nimber.
+    pub fn nimber(n: usize) -> GameValue {
+        let options = (0..n).map(GameValue::nimber).collect::<Vec<_>>();
+        GameValue::from_canonical_options(options.clone(), options)
+    }
+
+    /// Black's options, in no particular order.
+    pub fn left(&self) -> &[GameValue] {
+        &self.0.left
+    }
+
+    /// White's options, in no particular order.
+    pub fn right(&self) -> &[GameValue] {
+        &self.0.right
+    }
+
+    /// The value as a number, if it is one.
+    pub fn as_number(&self) -> Option<Dyadic> {
+        let left = self
+            .left()
+            .iter()
+            .map(GameValue::as_number)
+            .collect::<Option<Vec<_>>>()?;
+        let right = self
+            .right()
+            .iter()
+            .map(GameValue::as_number)
+            .collect::<Option<Vec<_>>>()?;
+
+        let low = left.into_iter().max();
+        let high = right.into_iter().min();
+        if let (Some(low), Some(high)) = (low, high) {
+            if low >= high {
+                return None;
+            }
+        }
+
+        Some(Dyadic::simplest_between(low, high))
+    }
+
+    /// The value as a nimber `*n`, if it is one.
+    pub fn as_nimber(&self) -> Option<usize> {
+        let mut left = self
+            .left()
+            .iter()
+            .map(GameValue::as_nimber)
+            .collect::<Option<Vec<_>>>()?;
+        let mut right = self
+            .right()
+            .iter()
+            .map(GameValue::as_nimber)
+            .collect::<Option<Vec<_>>>()?;
+        left.sort_unstable();
+        right.sort_unstable();
+
+        let n = left.len();
+        (left == right && left.into_iter().eq(0..n)).then_some(n)
+    }
+
+    /// Who wins when `first` moves first and both players play perfectly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{GameValue, Piece};
+    ///
+    /// let star = GameValue::nimber(1);
+    ///
+    /// assert_eq!(star.winner(Piece::BLACK), Piece::BLACK);
+    /// assert_eq!(GameValue::zero().winner(Piece::BLACK), Piece::WHITE);
+    /// ```
+    pub fn winner(&self, first: Piece) -> Piece {
+        let zero = GameValue::zero();
+
+        match (zero.leq(self), self.leq(&zero)) {
+            (true, true) => first.opponent(),
+            (true, false) => Piece::BLACK,
+            (false, true) => Piece::WHITE,
+            (false, false) => first,
+        }
+    }
+
+    /// Whether Right wins this game minus `other` moving second, meaning this game is no
+    /// better for Left than `other`.
+    fn leq(&self, other: &GameValue) -> bool {
+        if Rc::ptr_eq(&self.0, &other.0) {
+            return true;
+        }
+
+        !self.left().iter().any(|option| other.leq(option))
+            && !other.right().iter().any(|option| option.leq(self))
+    }
+
+    /// The highest number Black can reach when moving first, with White playing to keep it
+    /// low.
+    fn left_stop(&self) -> Option<Dyadic> {
+        match self.as_number() {
+            Some(number) => Some(number),
+            None => self.left().iter().filter_map(GameValue::right_stop).max(),
+        }
+    }
+
+    /// The lowest number White can reach when moving first, with Black playing to keep it high.
+    fn right_stop(&self) -> Option<Dyadic> {
+        match self.as_number() {
+            Some(number) => Some(number),
+            None => self.right().iter().filter_map(GameValue::left_stop).min(),
+        }
+    }
+
+    /// Build a value from options that are already canonical, ordering them by hash so that
+    /// equal values are built identically.
+    fn from_canonical_options(mut left: Vec<GameValue>, mut right: Vec<GameValue>) -> GameValue {
+        left.sort_by_key(|option| option.0.hash);
+        right.sort_by_key(|option| option.0.hash);
+
+        let mut hash = 0x6761_6d65_u64;
+        for option in &left {
+            hash = mix(hash ^ option.0.hash);
+        }
+        hash = mix(hash ^ 0x7c);
+        for option in &right {
+            hash = mix(hash ^ option.0.hash);
+        }
+
+        GameValue(Rc::new(Form { left, right, hash }))
+    }
+
+    /// The infinitesimals `↑`, `⇑`, `↓` and `⇓`, with and without a `*`, paired with their
+    /// names.
+    fn named_infinitesimals() -> Vec<(GameValue, &'static str)> {
+        let star = GameValue::nimber(1);
+        let up = GameValue::new([GameValue::zero()], [star.clone()]);
+        let double_up = up.clone() + up.clone();
+
+        vec![
+            (up.clone(), "↑"),
+            (up.clone() + star.clone(), "↑*"),
+            (-up.clone(), "↓"),
+            (-up + star.clone(), "↓*"),
+            (double_up.clone(), "⇑"),
+            (double_up.clone() + star.clone(), "⇑*"),
+            (-double_up.clone(), "⇓"),
+            (-double_up + star, "⇓*"),
+        ]
+    }
+}
+
+impl PartialEq for GameValue {
+    fn eq(&self, other: &GameValue) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+            || (self.0.hash == other.0.hash
+                && self.left() == other.left()
+                && self.right() == other.right())
+    }
+}
+
+impl Eq for GameValue {}
+
+impl Hash for GameValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash.hash(state);
+    }
+}
+
+impl PartialOrd for GameValue {
+    fn partial_cmp(&self, other: &GameValue) -> Option<Ordering> {
+        match (self.leq(other), other.leq(self)) {
+            (true, true) => Some(Ordering::Equal),
+            (true, false) => Some(Ordering::Less),
+            (false, true) => Some(Ordering::Greater),
+            (false, false) => None,
+        }
+    }
+}
+
+/// The disjunctive sum, where each player on their turn moves in either game.
+impl Add for GameValue {
+    type Output = GameValue;
+
+    fn add(self, rhs: GameValue) -> GameValue {
+        if self.left().is_empty() && self.right().is_empty() {
+            return rhs;
+        }
+        if rhs.left().is_empty() && rhs.right().is_empty() {
+            return self;
+        }
+
+        let left = self
+            .left()
+            .iter()
+            .map(|option| option.clone() + rhs.clone())
+            .chain(
+                rhs.left()
+                    .iter()
+                    .map(|option| self.clone() + option.clone()),
+            );
+        let right = self
+            .right()
+            .iter()
+            .map(|option| option.clone() + rhs.clone())
+            .chain(
+                rhs.right()
+                    .iter()
+                    .map(|option| self.clone() + option.clone()),
+            );
+
+        GameValue::new(left.collect::<Vec<_>>(), right.collect::<Vec<_>>())
+    }
+}
+
+/// The same game with the players' roles swapped.
+impl Neg for GameValue {
+    type Output = GameValue;
+
+    fn neg(self) -> GameValue {
+        GameValue::from_canonical_options(
+            self.right().iter().cloned().map(Neg::neg).collect(),
+            self.left().iter().cloned().map(Neg::neg).collect(),
+        )
+    }
+}
+
+impl Display for GameValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(number) = self.as_number() {
+            return write!(f, "{}", number);
+        }
+
+        // A number plus an infinitesimal, which Black and White both stop at when moving first
+        if let (Some(left_stop), Some(right_stop)) = (self.left_stop(), self.right_stop()) {
+            if left_stop == right_stop {
+                let prefix = if left_stop == Dyadic::integer(0) {
+                    String::new()
+                } else {
+                    left_stop.to_string()
+                };
+                let infinitesimal = self.clone() + -GameValue::number(left_stop);
+
+                match infinitesimal.as_nimber() {
+                    Some(1) => return write!(f, "{}*", prefix),
+                    Some(n) => return write!(f, "{}*{}", prefix, n),
+                    None => {}
+                }
+
+                let named = GameValue::named_infinitesimals()
+                    .into_iter()
+                    .find(|(value, _)| *value == infinitesimal);
+                if let Some((_, name)) = named {
+                    return write!(f, "{}{}", prefix, name);
+                }
+            }
+        }
+
+        // A switch between two numbers the same distance either side of zero
+        if let ([left], [right]) = (self.left(), self.right()) {
+            if let (Some(left), Some(right)) = (left.as_number(), right.as_number()) {
+                if left == -right {
+                    return write!(f, "±{}", left);
+                }
+            }
+        }
+
+        let join = |options: &[GameValue]| {
+            options
+                .iter()
+                .map(GameValue::to_string)
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        write!(f, "{{{}|{}}}", join(self.left()), join(self.right()))
+    }
+}
+
+/// Keep only the options that no other option beats, where `worse(a, b)` says `b` is at least
+/// as good as `a`. Equal options are canonical, so they're identical and only one is kept.
+fn undominated(
+    mut options: Vec<GameValue>,
+    worse: impl Fn(&GameValue, &GameValue) -> bool,
+) -> Vec<GameValue> {
+    options.sort_by_key(|option| option.0.hash);
+    options.dedup();
+
+    (0..options.len())
+        .filter(|&index| {
+            !options
+                .iter()
+                .enumerate()
+                .any(|(other, option)| other != index && worse(&options[index], option))
+        })
+        .map(|index| options[index].clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Dyadic, GameValue};
+
+    fn number(numerator: i64, exponent: u32) -> GameValue {
+        GameValue::number(Dyadic::new(numerator, exponent))
+    }
+
+    #[test]
+    fn numbers_are_canonical() {
+        let half = GameValue::new([GameValue::zero()], [number(1, 0)]);
+        assert_eq!(half, number(1, 1));
+        assert_eq!(half.to_string(), "1/2");
+
+        // -1 is dominated by 0, leaving {0|1}
+        let dominated = GameValue::new([GameValue::zero(), number(-1, 0)], [number(1, 0)]);
+        assert_eq!(dominated, half);
+
+        assert_eq!(half.clone() + half.clone(), number(1, 0));
+        assert_eq!((number(3, 2) + -number(5, 1)).to_string(), "-7/4");
+        assert_eq!(number(-3, 0).as_number(), Some(Dyadic::integer(-3)));
+    }
+
+    #[test]
+    fn reversible_options_are_bypassed() {
+        let star = GameValue::nimber(1);
+
+        // Whoever moves to * is answered by a move to 0, so this is just 0
+        assert_eq!(
+            GameValue::new([star.clone()], [star.clone()]),
+            GameValue::zero()
+        );
+
+        // Right answers Left's only move by moving to 0, and can't move first, so this is 0
+        assert_eq!(
+            GameValue::new([GameValue::nimber(2)], []),
+            GameValue::zero()
+        );
+
+        // Left's move from ↑ + ↑ to ↑ reverses through Right's reply of * to 0
+        let up = GameValue::new([GameValue::zero()], [star.clone()]);
+        let double_up = up.clone() + up.clone();
+        assert_eq!(double_up.left(), [GameValue::zero()]);
+        assert_eq!(double_up.right(), [up + star]);
+    }
+
+    #[test]
+    fn nimbers_add_like_xor() {
+        assert_eq!(
+            GameValue::nimber(3) + GameValue::nimber(5),
+            GameValue::nimber(6)
+        );
+        assert_eq!(GameValue::nimber(2).as_nimber(), Some(2));
+        assert_eq!(GameValue::nimber(2).to_string(), "*2");
+        assert_eq!(number(1, 0).as_nimber(), None);
+    }
+
+    #[test]
+    fn names_of_values() {
+        let star = GameValue::nimber(1);
+        let up = GameValue::new([GameValue::zero()], [star.clone()]);
+
+        assert_eq!(GameValue::zero().to_string(), "0");
+        assert_eq!(star.to_string(), "*");
+        assert_eq!((up.clone() + up.clone()).to_string(), "⇑");
+        assert_eq!((-up.clone() + star.clone()).to_string(), "↓*");
+        assert_eq!((number(1, 1) + up.clone()).to_string(), "1/2↑");
+        assert_eq!((number(-2, 0) + GameValue::nimber(3)).to_string(), "-2*3");
+        assert_eq!(
+            GameValue::new([number(1, 0)], [number(-1, 0)]).to_string(),
+            "±1"
+        );
+        assert_eq!(
+            GameValue::new([number(2, 0)], [number(0, 0)]).to_string(),
+            "{2|0}"
+        );
+    }
+
+    #[test]
+    fn comparisons() {
+        let star = GameValue::nimber(1);
+        let up = GameValue::new([GameValue::zero()], [star.clone()]);
+
+        assert!(up > GameValue::zero());
+        assert!(up < number(1, 5));
+        assert_eq!(up.partial_cmp(&star), None);
+        assert!(-up.clone() < GameValue::zero());
+    }
+}
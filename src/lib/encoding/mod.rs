@@ -0,0 +1,5 @@
+mod position;
+mod stream;
+
+pub use position::EncodedPosition;
+pub use stream::{PositionReader, PositionWriter};
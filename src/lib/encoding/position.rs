@@ -0,0 +1,334 @@
+use crate::{Board, KonaneError, Piece, Square};
+
+/// The bytes before the points: the width, the height and the side to move.
+pub(super) const HEADER_LEN: usize = 3;
+
+/// The two bits stored for each kind of point. `0b11` is never written.
+const EMPTY_BITS: u8 = 0b00;
+const BLACK_BITS: u8 = 0b01;
+const WHITE_BITS: u8 = 0b10;
+
+/// A board and the player to move, in a compact binary encoding, read straight from a slice of
+/// bytes without copying or unpacking them.
+///
+/// The encoding starts with three bytes: the board's width, its height, and the side to move,
+/// `0` for Black or `1` for White. The points follow two bits each, row by row from the top
+/// left, packed four to a byte starting from the lowest bits. `00` is empty, `01` is black and
+/// `10` is white, and any bits left over in the last byte are zero. A standard 6x6 board takes
+/// 12 bytes and the largest board takes 103.
+///
+/// Positions are written with [`Board::encode`] and read back with [`EncodedPosition::new`],
+/// which checks every byte so that [`EncodedPosition::to_board`] gives back exactly the board
+/// that was encoded. Only the pieces are kept, not the moves that led to them.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{Board, EncodedPosition, Piece};
+///
+/// let board = Board::default();
+/// let bytes = board.encode(Piece::WHITE);
+/// assert_eq!(bytes.len(), 12);
+///
+/// let position = EncodedPosition::new(&bytes).unwrap();
+/// assert_eq!((position.width(), position.height()), (6, 6));
+/// assert_eq!(position.side_to_move(), Piece::WHITE);
+/// assert_eq!(position.get_piece((0, 0)), Ok(Piece::BLACK));
+/// assert_eq!(position.to_board(), board);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncodedPosition<'a> {
+    bytes: &'a [u8],
+}
+
+impl Board {
+    /// Encode the board and `side_to_move` in the format read by [`EncodedPosition`]. Anything
+    /// but White to move is stored as Black, just as in [`Board::hash_key`].
+    pub fn encode(&self, side_to_move: Piece) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(EncodedPosition::encoded_len(self.width(), self.height()));
+        self.encode_into(side_to_move, &mut bytes);
+        bytes
+    }
+
+    /// Append the encoding of the board and `side_to_move` to `bytes`, so that one buffer can
+    /// be reused for many positions.
+    pub fn encode_into(&self, side_to_move: Piece, bytes: &mut Vec<u8>) {
+        let start = bytes.len();
+        bytes.push(self.width() as u8);
+        bytes.push(self.height() as u8);
+        bytes.push(u8::from(side_to_move == Piece::WHITE));
+        bytes.resize(
+            start + EncodedPosition::encoded_len(self.width(), self.height()),
+            0,
+        );
+
+        for (index, square) in self.squares().enumerate() {
+            let bits = match self.get_piece(square) {
+                Ok(Piece::BLACK) => BLACK_BITS,
+                Ok(Piece::WHITE) => WHITE_BITS,
+                _ => EMPTY_BITS,
+            };
+            bytes[start + HEADER_LEN + index / 4] |= bits << (index % 4 * 2);
+        }
+    }
+}
+
+impl<'a> EncodedPosition<'a> {
+    /// The number of bytes a position on a board of this size takes up.
+    pub fn encoded_len(width: usize, height: usize) -> usize {
+        HEADER_LEN + (width * height).div_ceil(4)
+    }
+
+    /// Read a position that takes up all of `bytes`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, EncodedPosition, KonaneError, Piece};
+    ///
+    /// let bytes = Board::default().encode(Piece::BLACK);
+    ///
+    /// assert!(EncodedPosition::new(&bytes).is_ok());
+    /// assert_eq!(
+    ///     EncodedPosition::new(&bytes[..11]),
+    ///     Err(KonaneError::InvalidEncoding(
+    ///         "a 6x6 position takes 12 bytes but there are 11".to_string()
+    ///     ))
+    /// );
+    /// ```
+    pub fn new(bytes: &'a [u8]) -> Result<EncodedPosition<'a>, KonaneError> {
+        match EncodedPosition::split_first(bytes)? {
+            (position, []) => Ok(position),
+            (position, rest) => Err(KonaneError::InvalidEncoding(format!(
+                "a {}x{} position takes {} bytes but there are {}",
+                position.width(),
+                position.height(),
+                position.bytes.len(),
+                position.bytes.len() + rest.len()
+            ))),
+        }
+    }
+
+    /// Check the width, height and side to move a position starts with, returning how many
+    /// bytes the whole position takes.
+    pub(super) fn check_header(header: [u8; HEADER_LEN]) -> Result<usize, KonaneError> {
+        let [width, height, side] = header;
+        let (width, height) = (width as usize, height as usize);
+
+        let valid = 1..=Board::MAX_SIZE;
+        if !valid.contains(&width) || !valid.contains(&height) {
+            return Err(KonaneError::InvalidSize { width, height });
+        }
+        if side > 1 {
+            return Err(KonaneError::InvalidEncoding(format!(
+                "unknown side to move {}",
+                side
+            )));
+        }
+
+        Ok(EncodedPosition::encoded_len(width, height))
+    }
+
+    /// Read the position at the start of `bytes`, returning it along with whatever follows,
+    /// so that a buffer holding many positions back to back can be walked without copying.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, EncodedPosition, Piece};
+    ///
+    /// let mut bytes = vec![];
+    /// Board::default().encode_into(Piece::BLACK, &mut bytes);
+    /// Board::create_empty().encode_into(Piece::WHITE, &mut bytes);
+    ///
+    /// let (first, rest) = EncodedPosition::split_first(&bytes).unwrap();
+    /// let (second, rest) = EncodedPosition::split_first(rest).unwrap();
+    ///
+    /// assert_eq!(first.to_board(), Board::default());
+    /// assert_eq!(second.side_to_move(), Piece::WHITE);
+    /// assert!(rest.is_empty());
+    /// ```
+    pub fn split_first(bytes: &'a [u8]) -> Result<(EncodedPosition<'a>, &'a [u8]), KonaneError> {
+        let invalid = |reason: String| Err(KonaneError::InvalidEncoding(reason));
+
+        let &[width, height, side, ..] = bytes else {
+            return invalid(format!(
+                "a position starts with {} bytes but there are {}",
+                HEADER_LEN,
+                bytes.len()
+            ));
+        };
+        let len = EncodedPosition::check_header([width, height, side])?;
+        let (width, height) = (width as usize, height as usize);
+        if bytes.len() < len {
+            return invalid(format!(
+                "a {}x{} position takes {} bytes but there are {}",
+                width,
+                height,
+                len,
+                bytes.len()
+            ));
+        }
+
+        let (bytes, rest) = bytes.split_at(len);
+        let position = EncodedPosition { bytes };
+
+        for index in 0..width * height {
+            if position.bits(index) == 0b11 {
+                let square = Square::from_index(index, width);
+                return invalid(format!("unknown point at {}", square));
+            }
+        }
+        let used = (width * height) % 4 * 2;
+        if used > 0 && bytes[len - 1] >> used != 0 {
+            return invalid("the bits after the last point aren't zero".to_string());
+        }
+
+        Ok((position, rest))
+    }
+
+    /// The number of columns on the board.
+    pub fn width(&self) -> usize {
+        self.bytes[0] as usize
+    }
+
+    /// The number of rows on the board.
+    pub fn height(&self) -> usize {
+        self.bytes[1] as usize
+    }
+
+    /// The player whose turn it is.
+    pub fn side_to_move(&self) -> Piece {
+        match self.bytes[2] {
+            0 => Piece::BLACK,
+            _ => Piece::WHITE,
+        }
+    }
+
+    /// The piece on a point, read straight from the encoding.
+    pub fn get_piece(&self, square: impl Into<Square>) -> Result<Piece, KonaneError> {
+        let square = square.into();
+        if square.row() >= self.height() || square.col() >= self.width() {
+            return Err(KonaneError::OutOfBounds(square));
+        }
+
+        Ok(match self.bits(square.index(self.width())) {
+            BLACK_BITS => Piece::BLACK,
+            WHITE_BITS => Piece::WHITE,
+            _ => Piece::EMPTY,
+        })
+    }
+
+    /// Unpack the position into a board.
+    pub fn to_board(&self) -> Board {
+        let mut board = Board::with_size(self.width(), self.height())
+            .expect("the size was checked when the position was read");
+
+        for index in 0..self.width() * self.height() {
+            let piece = match self.bits(index) {
+                BLACK_BITS => Piece::BLACK,
+                WHITE_BITS => Piece::WHITE,
+                _ => continue,
+            };
+            board
+                .set_piece(Square::from_index(index, self.width()), piece)
+                .expect("every index is on the board");
+        }
+
+        board
+    }
+
+    /// The encoded bytes, header included.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// The two bits stored for the point with the given index.
+    fn bits(&self, index: usize) -> u8 {
+        self.bytes[HEADER_LEN + index / 4] >> (index % 4 * 2) & 0b11
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Board, BoardBuilder, EncodedPosition, KonaneError, Piece, Setup};
+
+    #[test]
+    fn round_trips_every_size() {
+        for width in 1..=Board::MAX_SIZE {
+            for height in 1..=Board::MAX_SIZE {
+                let mut board = BoardBuilder::new()
+                    .size(width, height)
+                    .setup(Setup::Standard)
+                    .build()
+                    .unwrap();
+                let squares = board.squares().collect::<Vec<_>>();
+                for square in squares.into_iter().step_by(3) {
+                    let _ = board.set_piece(square, Piece::EMPTY);
+                }
+
+                for side in [Piece::BLACK, Piece::WHITE] {
+                    let bytes = board.encode(side);
+                    assert_eq!(bytes.len(), EncodedPosition::encoded_len(width, height));
+
+                    let position = EncodedPosition::new(&bytes).unwrap();
+                    assert_eq!(position.side_to_move(), side);
+                    assert_eq!(position.to_board(), board);
+                    assert_eq!(position.as_bytes(), &bytes[..]);
+
+                    for square in board.squares() {
+                        assert_eq!(position.get_piece(square), board.get_piece(square));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn packs_two_bits_per_point() {
+        let mut board = Board::with_size(3, 2).unwrap();
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((0, 1), Piece::WHITE);
+        let _ = board.set_piece((1, 2), Piece::BLACK);
+
+        assert_eq!(board.encode(Piece::WHITE), vec![3, 2, 1, 0b1001, 0b0100]);
+        assert_eq!(
+            Board::with_size(1, 1).unwrap().encode(Piece::EMPTY),
+            vec![1, 1, 0, 0]
+        );
+    }
+
+    #[test]
+    fn rejects_bad_bytes() {
+        let invalid = |reason: &str| Err(KonaneError::InvalidEncoding(reason.to_string()));
+
+        assert_eq!(
+            EncodedPosition::new(&[3, 2]),
+            invalid("a position starts with 3 bytes but there are 2")
+        );
+        assert_eq!(
+            EncodedPosition::new(&[0, 2, 0]),
+            Err(KonaneError::InvalidSize {
+                width: 0,
+                height: 2
+            })
+        );
+        assert_eq!(
+            EncodedPosition::new(&[3, 2, 2, 0, 0]),
+            invalid("unknown side to move 2")
+        );
+        assert_eq!(
+            EncodedPosition::new(&[3, 2, 0, 0b1100_0000, 0]),
+            invalid("unknown point at a2")
+        );
+        assert_eq!(
+            EncodedPosition::new(&[3, 2, 0, 0, 0b0001_0000]),
+            invalid("the bits after the last point aren't zero")
+        );
+        assert_eq!(
+            EncodedPosition::new(&[3, 2, 0, 0, 0, 0]),
+            invalid("a 3x2 position takes 5 bytes but there are 6")
+        );
+    }
+}
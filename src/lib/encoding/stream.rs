@@ -0,0 +1,268 @@
+use std::io::{self, ErrorKind, Read, Write};
+
+use super::position::{EncodedPosition, HEADER_LEN};
+use crate::{Board, Piece};
+
+/// The bytes every file of positions starts with: a tag and the version of the encoding.
+const FILE_HEADER: [u8; 5] = *b"KNPS\x01";
+
+/// Writes positions one after another in the [binary encoding](EncodedPosition), after a short
+/// header identifying the file.
+///
+/// Nothing is buffered beyond the position being written, so wrap the destination in a
+/// [`std::io::BufWriter`] when writing many positions to a file.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{Board, Piece, PositionReader, PositionWriter};
+///
+/// let mut writer = PositionWriter::new(vec![]).unwrap();
+/// writer.write(&Board::default(), Piece::BLACK).unwrap();
+/// writer.write(&Board::create_empty(), Piece::WHITE).unwrap();
+/// assert_eq!(writer.count(), 2);
+/// let bytes = writer.into_inner().unwrap();
+///
+/// let mut reader = PositionReader::new(&bytes[..]).unwrap();
+/// let first = reader.read_position().unwrap().unwrap();
+/// assert_eq!(first.to_board(), Board::default());
+///
+/// let second = reader.read_position().unwrap().unwrap();
+/// assert_eq!(second.side_to_move(), Piece::WHITE);
+/// assert!(reader.read_position().unwrap().is_none());
+/// ```
+#[derive(Debug)]
+pub struct PositionWriter<W: Write> {
+    writer: W,
+    buffer: Vec<u8>,
+    count: u64,
+}
+
+impl<W: Write> PositionWriter<W> {
+    /// Start a file of positions by writing its header to `writer`.
+    pub fn new(mut writer: W) -> io::Result<PositionWriter<W>> {
+        writer.write_all(&FILE_HEADER)?;
+
+        Ok(PositionWriter {
+            writer,
+            buffer: vec![],
+            count: 0,
+        })
+    }
+
+    /// Encode and write a board and the player to move.
+    pub fn write(&mut self, board: &Board, side_to_move: Piece) -> io::Result<()> {
+        self.buffer.clear();
+        board.encode_into(side_to_move, &mut self.buffer);
+        self.writer.write_all(&self.buffer)?;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Write a position that's already encoded, such as one from a [`PositionReader`].
+    pub fn write_encoded(&mut self, position: EncodedPosition) -> io::Result<()> {
+        self.writer.write_all(position.as_bytes())?;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// The number of positions written so far.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Flush everything written and hand back the destination.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Reads positions written by a [`PositionWriter`], one at a time, reusing a single buffer so
+/// that a file far bigger than memory can be streamed through.
+///
+/// [`PositionReader::read_position`] lends out each position without unpacking it, and the
+/// reader is also an [`Iterator`] over owned boards and sides to move. Positions that can't be
+/// decoded are reported as [`ErrorKind::InvalidData`] errors wrapping the
+/// [`KonaneError`](crate::KonaneError) that explains why, and a file that stops part way
+/// through a position as [`ErrorKind::UnexpectedEof`].
+///
+/// Reading is unbuffered, so wrap a file in a [`std::io::BufReader`] first.
+#[derive(Debug)]
+pub struct PositionReader<R: Read> {
+    reader: R,
+    buffer: Vec<u8>,
+}
+
+impl<R: Read> PositionReader<R> {
+    /// Start reading from `reader`, checking it starts with the header a [`PositionWriter`]
+    /// writes.
+    pub fn new(mut reader: R) -> io::Result<PositionReader<R>> {
+        let mut header = [0; FILE_HEADER.len()];
+        reader.read_exact(&mut header)?;
+        if header != FILE_HEADER {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                "this isn't a file of encoded positions",
+            ));
+        }
+
+        Ok(PositionReader {
+            reader,
+            buffer: vec![],
+        })
+    }
+
+    /// Read the next position, or `None` once the file has ended cleanly. The position borrows
+    /// the reader's buffer, so it has to be dropped before the next one is read.
+    pub fn read_position(&mut self) -> io::Result<Option<EncodedPosition<'_>>> {
+        let mut header = [0; HEADER_LEN];
+        let mut filled = 0;
+        while filled < header.len() {
+            match self.reader.read(&mut header[filled..]) {
+                Ok(0) if filled == 0 => return Ok(None),
+                Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+                Ok(read) => filled += read,
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Err(error),
+            }
+        }
+
+        // Check the header before reading on, so a corrupt one can't swallow what follows
+        let len = EncodedPosition::check_header(header)
+            .map_err(|error| io::Error::new(ErrorKind::InvalidData, error))?;
+        self.buffer.clear();
+        self.buffer.extend_from_slice(&header);
+        self.buffer.resize(len, 0);
+        self.reader.read_exact(&mut self.buffer[header.len()..])?;
+
+        EncodedPosition::new(&self.buffer)
+            .map(Some)
+            .map_err(|error| io::Error::new(ErrorKind::InvalidData, error))
+    }
+}
+
+impl<R: Read> Iterator for PositionReader<R> {
+    type Item = io::Result<(Board, Piece)>;
+
+    fn next(&mut self) -> Option<io::Result<(Board, Piece)>> {
+        self.read_position()
+            .map(|position| position.map(|position| (position.to_board(), position.side_to_move())))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::ErrorKind;
+
+    use crate::{
+        Board, BoardBuilder, EncodedPosition, Game, KonaneError, Piece, PositionReader,
+        PositionWriter,
+    };
+
+    /// Every position from a whole game on a board of this size, with the player to move.
+    fn positions(width: usize, height: usize) -> Vec<(Board, Piece)> {
+        let mut game = Game::opening(BoardBuilder::new().size(width, height).build().unwrap());
+        game.remove((0, 0)).unwrap();
+        game.remove((0, 1)).unwrap();
+
+        let mut positions = vec![];
+        while game.result().is_none() {
+            positions.push((game.board().clone(), game.side_to_move()));
+            let moves = game.legal_moves();
+            game.play(moves[game.ply() % moves.len()]).unwrap();
+        }
+
+        positions
+    }
+
+    #[test]
+    fn streams_many_positions() {
+        let written = [(6, 6), (8, 8), (5, 7), (20, 20)]
+            .into_iter()
+            .flat_map(|(width, height)| positions(width, height))
+            .collect::<Vec<_>>();
+
+        let mut writer = PositionWriter::new(vec![]).unwrap();
+        for (board, side) in &written {
+            writer.write(board, *side).unwrap();
+        }
+        assert_eq!(writer.count(), written.len() as u64);
+        let bytes = writer.into_inner().unwrap();
+
+        let read = PositionReader::new(&bytes[..])
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(read, written);
+
+        // Copying encoded positions straight across gives an identical file
+        let mut reader = PositionReader::new(&bytes[..]).unwrap();
+        let mut copy = PositionWriter::new(vec![]).unwrap();
+        while let Some(position) = reader.read_position().unwrap() {
+            copy.write_encoded(position).unwrap();
+        }
+        assert_eq!(copy.into_inner().unwrap(), bytes);
+    }
+
+    #[test]
+    fn reports_broken_files() {
+        assert_eq!(
+            PositionReader::new(&b"KNPS\x02"[..]).unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+        assert_eq!(
+            PositionReader::new(&b"KN"[..]).unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+
+        let mut writer = PositionWriter::new(vec![]).unwrap();
+        writer.write(&Board::default(), Piece::BLACK).unwrap();
+        let bytes = writer.into_inner().unwrap();
+
+        let mut reader = PositionReader::new(&bytes[..bytes.len() - 1]).unwrap();
+        let error = reader.read_position().unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+
+        let mut reader = PositionReader::new(&bytes[..7]).unwrap();
+        let error = reader.read_position().unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+
+        let mut bad = bytes.clone();
+        bad[7] = 2;
+        let mut reader = PositionReader::new(&bad[..]).unwrap();
+        let error = reader.read_position().unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+        assert_eq!(
+            error.into_inner().unwrap().downcast::<KonaneError>().ok(),
+            Some(Box::new(KonaneError::InvalidEncoding(
+                "unknown side to move 2".to_string()
+            )))
+        );
+
+        // A corrupt header is reported without reading any further
+        let mut bad = bytes.clone();
+        bad[5] = 200;
+        let mut source = &bad[..];
+        let mut reader = PositionReader::new(&mut source).unwrap();
+        let error = reader.read_position().unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+        assert_eq!(
+            error.into_inner().unwrap().downcast::<KonaneError>().ok(),
+            Some(Box::new(KonaneError::InvalidSize {
+                width: 200,
+                height: 6
+            }))
+        );
+        assert_eq!(source, &bytes[8..]);
+
+        let mut reader = PositionReader::new(&bytes[..]).unwrap();
+        assert!(reader.read_position().unwrap().is_some());
+        assert!(reader.read_position().unwrap().is_none());
+        assert_eq!(
+            EncodedPosition::encoded_len(6, 6),
+            bytes.len() - b"KNPS\x01".len()
+        );
+    }
+}
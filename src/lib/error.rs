@@ -0,0 +1,97 @@
+use std::{error::Error, fmt::Display};
+
+use crate::{Phase, Piece, Square};
+
+/// The reasons an operation on a board or game can be rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KonaneError {
+    /// The point isn't on the board.
+    OutOfBounds(Square),
+    /// A board can't be made with these dimensions.
+    InvalidSize { width: usize, height: usize },
+    /// A board pattern couldn't be understood.
+    InvalidPattern(String),
+    /// A position written in notation couldn't be understood.
+    InvalidNotation(String),
+    /// A position in the binary encoding couldn't be decoded.
+    InvalidEncoding(String),
+    /// A game record couldn't be read, at this line and column, counting from one.
+    InvalidRecord {
+        line: usize,
+        column: usize,
+        reason: String,
+    },
+    /// A board diagram couldn't be read, at this line and column, counting from one.
+    InvalidDiagram {
+        line: usize,
+        column: usize,
+        reason: String,
+    },
+    /// There is no piece on the point to move.
+    EmptyPoint(Square),
+    /// The piece belongs to the player who isn't moving.
+    WrongSide { expected: Piece, found: Piece },
+    /// The move doesn't jump over anything.
+    NotAJump,
+    /// The point jumped over doesn't hold an enemy piece.
+    NoEnemyToJump(Square),
+    /// The move would take the piece off the edge of the board.
+    JumpOffBoard,
+    /// The point the piece would land on already holds a piece.
+    LandingOccupied(Square),
+    /// The piece on this point can't be removed during the opening.
+    IllegalRemoval(Square),
+    /// Only Black or White can be the player to move.
+    EmptySide,
+    /// The action can't be taken during this phase of the game.
+    WrongPhase(Phase),
+    /// The game has already been won.
+    GameOver { winner: Piece },
+    /// There is nothing left to take back.
+    NothingToUndo,
+}
+
+impl Display for KonaneError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KonaneError::OutOfBounds(square) => write!(f, "{} is off the board", square),
+            KonaneError::InvalidSize { width, height } => {
+                write!(f, "a board can't be {} wide and {} high", width, height)
+            }
+            KonaneError::InvalidPattern(reason) => write!(f, "invalid board pattern: {}", reason),
+            KonaneError::InvalidNotation(reason) => write!(f, "invalid notation: {}", reason),
+            KonaneError::InvalidEncoding(reason) => write!(f, "invalid encoding: {}", reason),
+            KonaneError::InvalidRecord {
+                line,
+                column,
+                reason,
+            } => write!(f, "invalid record at {}:{}: {}", line, column, reason),
+            KonaneError::InvalidDiagram {
+                line,
+                column,
+                reason,
+            } => write!(f, "invalid diagram at {}:{}: {}", line, column, reason),
+            KonaneError::EmptyPoint(square) => write!(f, "there is no piece at {}", square),
+            KonaneError::WrongSide { expected, found } => {
+                write!(f, "it's {:?}'s turn, not {:?}'s", expected, found)
+            }
+            KonaneError::NotAJump => write!(f, "a move must jump at least once"),
+            KonaneError::NoEnemyToJump(square) => {
+                write!(f, "there is no enemy piece at {} to jump over", square)
+            }
+            KonaneError::JumpOffBoard => write!(f, "the move would leave the board"),
+            KonaneError::LandingOccupied(square) => {
+                write!(f, "can't land on {} as it's already occupied", square)
+            }
+            KonaneError::IllegalRemoval(square) => {
+                write!(f, "the piece at {} can't be removed", square)
+            }
+            KonaneError::EmptySide => write!(f, "the side to move must be Black or White"),
+            KonaneError::WrongPhase(phase) => write!(f, "not allowed during the {:?} phase", phase),
+            KonaneError::GameOver { winner } => write!(f, "the game is over, {:?} won", winner),
+            KonaneError::NothingToUndo => write!(f, "there is nothing to undo"),
+        }
+    }
+}
+
+impl Error for KonaneError {}
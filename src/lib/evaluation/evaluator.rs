@@ -0,0 +1,37 @@
+use crate::{Board, Piece};
+
+/// Scores positions at the end of a search, where there's no time left to look further ahead.
+///
+/// Scores are from the point of view of the side to move, so a position that's good for
+/// `side` scores above zero and the same position scores below zero for their opponent. They
+/// must stay well short of [`WIN_SCORE`](crate::WIN_SCORE), so they can't be mistaken for a
+/// forced win or loss.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{Board, Evaluator, Piece, Searcher};
+///
+/// /// Prefers positions where the side to move has more pieces left.
+/// struct Material;
+///
+/// impl Evaluator for Material {
+///     fn evaluate(&self, board: &Board, side: Piece) -> i32 {
+///         board.count(side) as i32 - board.count(side.opponent()) as i32
+///     }
+/// }
+///
+/// let mut board = Board::create_empty();
+/// let _ = board.set_piece((0, 0), Piece::BLACK);
+/// let _ = board.set_piece((0, 1), Piece::WHITE);
+/// let _ = board.set_piece((5, 5), Piece::WHITE);
+///
+/// assert_eq!(Material.evaluate(&board, Piece::BLACK), -1);
+///
+/// let mut searcher = Searcher::new().with_evaluator(Material);
+/// assert!(searcher.best_move(&board, Piece::BLACK, 2).is_some());
+/// ```
+pub trait Evaluator {
+    /// Score `board` for `side`, who is to move.
+    fn evaluate(&self, board: &Board, side: Piece) -> i32;
+}
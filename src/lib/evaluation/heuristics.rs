@@ -0,0 +1,117 @@
+use super::Evaluator;
+use crate::{Board, Piece};
+
+/// The number of moves available to the side to move, less those available to their opponent.
+/// A player with no moves left has lost, so having more of them than the opponent is usually
+/// a good sign.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{Board, Evaluator, Mobility, Piece};
+///
+/// let mut board = Board::create_empty();
+/// let _ = board.set_piece((0, 0), Piece::BLACK);
+/// let _ = board.set_piece((0, 1), Piece::WHITE);
+/// let _ = board.set_piece((1, 0), Piece::WHITE);
+///
+/// assert_eq!(Mobility.evaluate(&board, Piece::BLACK), 2);
+/// assert_eq!(Mobility.evaluate(&board, Piece::WHITE), -2);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Mobility;
+
+impl Evaluator for Mobility {
+    fn evaluate(&self, board: &Board, side: Piece) -> i32 {
+        board.legal_moves(side).len() as i32 - board.legal_moves(side.opponent()).len() as i32
+    }
+}
+
+/// The number of safe moves available to the side to move, less those available to their
+/// opponent. A move is safe if it's still there whatever the other player does first, so it
+/// can be saved for later.
+///
+/// This looks one move ahead for each player, so it's a good deal slower than [`Mobility`].
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{Board, Evaluator, Piece, SafeMoves};
+///
+/// let mut board = Board::create_empty();
+/// let _ = board.set_piece((0, 0), Piece::BLACK);
+/// let _ = board.set_piece((0, 1), Piece::WHITE);
+/// let _ = board.set_piece((4, 4), Piece::BLACK);
+/// let _ = board.set_piece((4, 5), Piece::WHITE);
+/// let _ = board.set_piece((3, 5), Piece::BLACK);
+///
+/// // White can capture (3, 5) to take away Black's jump over (4, 5), but not the one over
+/// // (0, 1), so Black has one safe move. Both of White's jumps start from (4, 5), and Black's
+/// // jump from (3, 5) captures it, so White has none
+/// assert_eq!(SafeMoves.evaluate(&board, Piece::BLACK), 1);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SafeMoves;
+
+impl Evaluator for SafeMoves {
+    fn evaluate(&self, board: &Board, side: Piece) -> i32 {
+        let mut board = board.clone();
+
+        safe_moves(&mut board, side) as i32 - safe_moves(&mut board, side.opponent()) as i32
+    }
+}
+
+/// Count the moves `side` could make after any reply by their opponent.
+fn safe_moves(board: &mut Board, side: Piece) -> usize {
+    let mut safe = board.legal_moves(side);
+
+    for reply in board.legal_moves(side.opponent()) {
+        if safe.is_empty() {
+            break;
+        }
+
+        board
+            .apply_move(reply)
+            .expect("generated moves are always legal");
+        let remaining = board.legal_moves(side);
+        board.unmake_move().expect("the move was just applied");
+
+        safe.retain(|jump| remaining.contains(jump));
+    }
+
+    safe.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{testing::opened, Board, Evaluator, Mobility, Piece, SafeMoves};
+
+    #[test]
+    fn mobility_is_symmetric() {
+        let game = opened();
+        let board = game.board();
+
+        let black = Mobility.evaluate(board, Piece::BLACK);
+        assert_eq!(
+            black,
+            board.legal_moves(Piece::BLACK).len() as i32
+                - board.legal_moves(Piece::WHITE).len() as i32
+        );
+        assert_eq!(Mobility.evaluate(board, Piece::WHITE), -black);
+    }
+
+    #[test]
+    fn blocked_moves_are_not_safe() {
+        // White's only jump, from (2, 2) to (0, 2), captures (1, 2) and lands where the piece
+        // at (0, 0) was going. Either of Black's jumps stops it: the one from (0, 0) fills
+        // (0, 2), and the one from (1, 2) captures (2, 2)
+        let mut board = Board::create_empty();
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((0, 1), Piece::WHITE);
+        let _ = board.set_piece((2, 2), Piece::WHITE);
+        let _ = board.set_piece((1, 2), Piece::BLACK);
+
+        assert_eq!(Mobility.evaluate(&board, Piece::BLACK), 1);
+        assert_eq!(SafeMoves.evaluate(&board, Piece::BLACK), 0);
+    }
+}
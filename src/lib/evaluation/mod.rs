@@ -0,0 +1,7 @@
+mod evaluator;
+mod heuristics;
+mod weighted;
+
+pub use evaluator::Evaluator;
+pub use heuristics::{Mobility, SafeMoves};
+pub use weighted::Weighted;
@@ -0,0 +1,84 @@
+use std::fmt::Debug;
+
+use super::Evaluator;
+use crate::{Board, Piece};
+
+/// A weighted sum of other evaluators' scores.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{Board, Evaluator, Mobility, Piece, SafeMoves, Weighted};
+///
+/// let mut board = Board::create_empty();
+/// let _ = board.set_piece((0, 0), Piece::BLACK);
+/// let _ = board.set_piece((0, 1), Piece::WHITE);
+/// let _ = board.set_piece((1, 0), Piece::WHITE);
+///
+/// let evaluator = Weighted::new().with(1, Mobility).with(3, SafeMoves);
+///
+/// let mobility = Mobility.evaluate(&board, Piece::BLACK);
+/// let safe_moves = SafeMoves.evaluate(&board, Piece::BLACK);
+/// assert_eq!(evaluator.evaluate(&board, Piece::BLACK), mobility + 3 * safe_moves);
+/// ```
+#[derive(Default)]
+pub struct Weighted {
+    terms: Vec<(i32, Box<dyn Evaluator>)>,
+}
+
+impl Weighted {
+    /// A sum with no terms, which scores every position as zero.
+    pub fn new() -> Weighted {
+        Weighted::default()
+    }
+
+    /// Add `evaluator`'s score, multiplied by `weight`.
+    pub fn with(mut self, weight: i32, evaluator: impl Evaluator + 'static) -> Weighted {
+        self.terms.push((weight, Box::new(evaluator)));
+        self
+    }
+}
+
+impl Evaluator for Weighted {
+    fn evaluate(&self, board: &Board, side: Piece) -> i32 {
+        self.terms
+            .iter()
+            .map(|(weight, evaluator)| weight * evaluator.evaluate(board, side))
+            .sum()
+    }
+}
+
+impl Debug for Weighted {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let weights = self
+            .terms
+            .iter()
+            .map(|(weight, _)| weight)
+            .collect::<Vec<_>>();
+
+        f.debug_struct("Weighted")
+            .field("weights", &weights)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Board, Evaluator, Mobility, Piece, Weighted};
+
+    #[test]
+    fn weights_scale_and_add() {
+        let mut board = Board::create_empty();
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((0, 1), Piece::WHITE);
+
+        assert_eq!(Weighted::new().evaluate(&board, Piece::BLACK), 0);
+        assert_eq!(
+            Weighted::new()
+                .with(2, Mobility)
+                .with(-5, Mobility)
+                .evaluate(&board, Piece::BLACK),
+            -3
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use std::ops::{BitAnd, BitOr, Not};
+
+use super::Board;
+
+/// The number of 64 bit words needed to give every point on the largest board its own bit.
+const WORDS: usize = (Board::MAX_SIZE * Board::MAX_SIZE).div_ceil(64);
+
+/// A set of points on a board, one bit per point, numbered row by row from the top left.
+/// Boards wider than a single word spill over into the following words, so shifting by a
+/// whole row moves bits across word boundaries.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub(crate) struct Bitboard {
+    words: [u64; WORDS],
+}
+
+impl Bitboard {
+    /// A set containing every point from `0` up to, but not including, `len`.
+    pub(crate) fn first(len: usize) -> Bitboard {
+        let mut bitboard = Bitboard::default();
+        for index in 0..len {
+            bitboard.set(index);
+        }
+
+        bitboard
+    }
+
+    pub(crate) fn get(&self, index: usize) -> bool {
+        self.words[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    pub(crate) fn set(&mut self, index: usize) {
+        self.words[index / 64] |= 1 << (index % 64);
+    }
+
+    pub(crate) fn clear(&mut self, index: usize) {
+        self.words[index / 64] &= !(1 << (index % 64));
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    pub(crate) fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Move every point `distance` bits towards the end of the board, dropping any that fall
+    /// off the last word.
+    pub(crate) fn shift_up(&self, distance: usize) -> Bitboard {
+        let (word_shift, bit_shift) = (distance / 64, distance % 64);
+        let mut shifted = Bitboard::default();
+
+        for index in (word_shift..WORDS).rev() {
+            let source = index - word_shift;
+            let mut word = self.words[source] << bit_shift;
+            if bit_shift > 0 && source > 0 {
+                word |= self.words[source - 1] >> (64 - bit_shift);
+            }
+
+            shifted.words[index] = word;
+        }
+
+        shifted
+    }
+
+    /// Move every point `distance` bits towards the start of the board, dropping any that fall
+    /// off the first word.
+    pub(crate) fn shift_down(&self, distance: usize) -> Bitboard {
+        let (word_shift, bit_shift) = (distance / 64, distance % 64);
+        let mut shifted = Bitboard::default();
+
+        for index in 0..WORDS - word_shift.min(WORDS) {
+            let source = index + word_shift;
+            let mut word = self.words[source] >> bit_shift;
+            if bit_shift > 0 && source + 1 < WORDS {
+                word |= self.words[source + 1] << (64 - bit_shift);
+            }
+
+            shifted.words[index] = word;
+        }
+
+        shifted
+    }
+
+    /// Iterate over the index of every point in the set, in ascending order.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(index, &word)| {
+            let mut remaining = word;
+            std::iter::from_fn(move || {
+                if remaining == 0 {
+                    return None;
+                }
+
+                let bit = remaining.trailing_zeros() as usize;
+                remaining &= remaining - 1;
+                Some(index * 64 + bit)
+            })
+        })
+    }
+}
+
+impl BitAnd for Bitboard {
+    type Output = Bitboard;
+
+    fn bitand(mut self, rhs: Bitboard) -> Bitboard {
+        for (word, other) in self.words.iter_mut().zip(rhs.words) {
+            *word &= other;
+        }
+
+        self
+    }
+}
+
+impl BitOr for Bitboard {
+    type Output = Bitboard;
+
+    fn bitor(mut self, rhs: Bitboard) -> Bitboard {
+        for (word, other) in self.words.iter_mut().zip(rhs.words) {
+            *word |= other;
+        }
+
+        self
+    }
+}
+
+impl Not for Bitboard {
+    type Output = Bitboard;
+
+    fn not(mut self) -> Bitboard {
+        for word in self.words.iter_mut() {
+            *word = !*word;
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Bitboard;
+
+    #[test]
+    fn shifts_carry_across_words() {
+        let mut bitboard = Bitboard::default();
+        bitboard.set(0);
+        bitboard.set(63);
+        bitboard.set(100);
+
+        let up = bitboard.shift_up(70);
+        assert_eq!(up.iter().collect::<Vec<_>>(), vec![70, 133, 170]);
+
+        let down = up.shift_down(70);
+        assert_eq!(down, bitboard);
+
+        assert_eq!(bitboard.shift_down(64).iter().collect::<Vec<_>>(), vec![36]);
+    }
+
+    #[test]
+    fn set_operations() {
+        let low = Bitboard::first(10);
+        let mut odd = Bitboard::default();
+        (1..200).step_by(2).for_each(|index| odd.set(index));
+
+        assert_eq!((low & odd).count(), 5);
+        assert_eq!((low | odd).count(), 105);
+        assert!((low & !low).is_empty());
+
+        let mut cleared = low;
+        cleared.clear(3);
+        assert!(!cleared.get(3));
+        assert!(cleared.get(4));
+    }
+}
@@ -1,41 +1,87 @@
-use std::fmt::Display;
+use std::{
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
 
-use super::Piece;
+use super::{bitboard::Bitboard, zobrist, BoardBuilder, Direction, Move, Piece, Setup, Square};
+use crate::KonaneError;
 
-/// A 6 per side square playing board, containing 36 points.
-/// These points either contain a white, black, or no piece.
+/// A rectangular playing board, 6 per side unless built otherwise.
+/// Each point either contains a white, black, or no piece.
+///
+/// Pieces are stored as one bitboard per colour, so that moves for a whole side can be found
+/// by shifting and masking every piece at once.
+///
+/// Every move applied to the board is remembered, so that it can later be taken back with
+/// [`Board::unmake_move`]. Two boards are equal when they're the same size and hold the same
+/// pieces, whatever moves were played to get there.
+#[derive(Clone, Debug)]
 pub struct Board {
-    points: [Piece; 36],
+    width: usize,
+    height: usize,
+    black: Bitboard,
+    white: Bitboard,
+    /// The Zobrist key of the board's size and pieces, kept up to date as pieces change.
+    key: u64,
+    /// Every point on the board.
+    on_board: Bitboard,
+    /// Every point not in the leftmost column, which can therefore step left.
+    not_first_col: Bitboard,
+    /// Every point not in the rightmost column, which can therefore step right.
+    not_last_col: Bitboard,
+    undo_stack: Vec<Undo>,
 }
 
+/// Everything needed to take back an applied move.
+#[derive(Clone, Debug)]
+struct Undo {
+    jump: Move,
+    captured: Vec<Piece>,
+}
+
+impl PartialEq for Board {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self.black == other.black
+            && self.white == other.white
+    }
+}
+
+impl Eq for Board {}
+
+impl Hash for Board {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key.hash(state);
+    }
+}
+
+/// The standard starting position: a full checkerboard with Black in the top left corner.
 impl Default for Board {
     fn default() -> Self {
-        let mut board = Board::create_empty();
-        for i in (0..36).step_by(2) {
-            board.points[i] = Piece::BLACK;
-            board.points[i + 1] = Piece::WHITE;
-        }
-
-        board
+        BoardBuilder::new()
+            .setup(Setup::Standard)
+            .build()
+            .expect("the standard setup always fills the board")
     }
 }
 
+/// Draws the board as a grid, one row per line, with `B` for black, `W` for white and a space
+/// for an empty point, each followed by a space. [`Board::from_diagram`] reads it back.
 impl Display for Board {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in 0..6 {
+        for row in 0..self.height {
             writeln!(
                 f,
                 "{}",
-                self.points[row * 6..row * 6 + 6]
-                    .iter()
+                (0..self.width)
+                    .map(|col| self.piece_at(Square::new(row, col).index(self.width)))
                     .map(|point| {
-                        let point_char = String::from(match point {
+                        String::from(match point {
                             Piece::BLACK => 'B',
                             Piece::WHITE => 'W',
                             Piece::EMPTY => ' ',
-                        }) + " ";
-
-                        point_char
+                        }) + " "
                     })
                     .collect::<String>()
             )?;
@@ -46,6 +92,9 @@ impl Display for Board {
 }
 
 impl Board {
+    /// The most points allowed along either side of a board.
+    pub const MAX_SIZE: usize = 20;
+
     /// Create an empty board
     ///
     /// # Example
@@ -57,35 +106,131 @@ impl Board {
     ///
     /// for row in 0..5 {
     ///     for col in 0..5 {
-    ///         assert_eq!(board.get_piece(row, col), Some(Piece::EMPTY));
+    ///         assert_eq!(board.get_piece((row, col)), Ok(Piece::EMPTY));
     ///     }
     /// }
     /// ```
     pub fn create_empty() -> Board {
-        Board {
-            points: [Piece::EMPTY; 36],
+        Board::with_size(6, 6).expect("a 6x6 board is always valid")
+    }
+
+    /// Create an empty board with the given number of columns and rows. Both must be between
+    /// one and [`Board::MAX_SIZE`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, KonaneError, Piece, Square};
+    ///
+    /// let board = Board::with_size(10, 8).unwrap();
+    ///
+    /// assert_eq!(board.width(), 10);
+    /// assert_eq!(board.height(), 8);
+    /// assert_eq!(board.get_piece((7, 9)), Ok(Piece::EMPTY));
+    /// assert_eq!(
+    ///     board.get_piece((9, 7)),
+    ///     Err(KonaneError::OutOfBounds(Square::new(9, 7)))
+    /// );
+    ///
+    /// assert!(Board::with_size(0, 8).is_err());
+    /// assert!(Board::with_size(21, 8).is_err());
+    /// ```
+    pub fn with_size(width: usize, height: usize) -> Result<Board, KonaneError> {
+        let valid = 1..=Board::MAX_SIZE;
+        if !valid.contains(&width) || !valid.contains(&height) {
+            return Err(KonaneError::InvalidSize { width, height });
+        }
+
+        let on_board = Bitboard::first(width * height);
+        let mut not_first_col = on_board;
+        let mut not_last_col = on_board;
+        for row in 0..height {
+            not_first_col.clear(row * width);
+            not_last_col.clear(row * width + width - 1);
         }
+
+        Ok(Board {
+            width,
+            height,
+            black: Bitboard::default(),
+            white: Bitboard::default(),
+            key: zobrist::size_key(width, height),
+            on_board,
+            not_first_col,
+            not_last_col,
+            undo_stack: vec![],
+        })
     }
 
-    /// Get the piece at a given position
+    /// The number of columns on the board.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows on the board.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Every square on the board, row by row from the top left.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use konane_engine::{Board, Piece};
+    /// use konane_engine::{Board, Square};
+    ///
+    /// let board = Board::with_size(3, 2).unwrap();
+    /// let squares = board.squares().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(squares.len(), 6);
+    /// assert_eq!(squares[1], Square::new(0, 1));
+    /// assert_eq!(squares[3], Square::new(1, 0));
+    /// ```
+    pub fn squares(&self) -> impl Iterator<Item = Square> {
+        let width = self.width;
+        (0..self.width * self.height).map(move |index| Square::from_index(index, width))
+    }
+
+    /// Get the piece at a given position, which may be a [`Square`] or a `(row, col)` tuple.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, KonaneError, Piece, Square};
     ///
     /// let board = Board::default();
-    /// assert_eq!(board.get_piece(0, 0), Some(Piece::BLACK));
+    /// assert_eq!(board.get_piece((0, 0)), Ok(Piece::BLACK));
+    /// assert_eq!(board.get_piece("b1".parse::<Square>().unwrap()), Ok(Piece::WHITE));
     ///
     /// // Off board range
-    /// assert_eq!(board.get_piece(6, 1), None);
+    /// assert_eq!(
+    ///     board.get_piece((6, 1)),
+    ///     Err(KonaneError::OutOfBounds(Square::new(6, 1)))
+    /// );
     /// ```
-    pub fn get_piece(&self, row: usize, col: usize) -> Option<Piece> {
-        if row > 5 || col > 5 {
-            return None;
+    pub fn get_piece(&self, square: impl Into<Square>) -> Result<Piece, KonaneError> {
+        let square = square.into();
+        if !self.contains(square) {
+            return Err(KonaneError::OutOfBounds(square));
         }
 
-        Some(self.points[row * 6 + col])
+        Ok(self.piece_at(square.index(self.width)))
+    }
+
+    /// Whether the square is on the board.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, Square};
+    ///
+    /// let board = Board::with_size(4, 3).unwrap();
+    ///
+    /// assert!(board.contains(Square::new(2, 3)));
+    /// assert!(!board.contains(Square::new(3, 2)));
+    /// ```
+    pub fn contains(&self, square: Square) -> bool {
+        square.row() < self.height && square.col() < self.width
     }
 
     /// Set the piece at a given location to a given piece type.
@@ -97,127 +242,709 @@ impl Board {
     ///
     /// let mut board = Board::create_empty();
     ///
-    /// let captured_piece = board.set_piece(0, 1, Piece::WHITE).unwrap();
+    /// let captured_piece = board.set_piece((0, 1), Piece::WHITE).unwrap();
     /// assert_eq!(captured_piece, Piece::EMPTY);
-    /// assert_eq!(board.get_piece(0, 1), Some(Piece::WHITE));
+    /// assert_eq!(board.get_piece((0, 1)), Ok(Piece::WHITE));
     ///
-    /// let captured_piece = board.set_piece(0, 1, Piece::BLACK).unwrap();
+    /// let captured_piece = board.set_piece((0, 1), Piece::BLACK).unwrap();
     /// assert_eq!(captured_piece, Piece::WHITE);
-    /// assert_eq!(board.get_piece(0, 1), Some(Piece::BLACK));
+    /// assert_eq!(board.get_piece((0, 1)), Ok(Piece::BLACK));
     /// ```
-    pub fn set_piece(&mut self, row: usize, col: usize, piece_type: Piece) -> Option<Piece> {
-        match self.get_piece(row, col) {
-            Some(piece) => {
-                self.points[row * 6 + col] = piece_type;
-                Some(piece)
-            }
-            None => None,
+    pub fn set_piece(
+        &mut self,
+        square: impl Into<Square>,
+        piece_type: Piece,
+    ) -> Result<Piece, KonaneError> {
+        let square = square.into();
+        let piece = self.get_piece(square)?;
+        let index = square.index(self.width);
+
+        self.key ^= zobrist::piece_key(piece, index) ^ zobrist::piece_key(piece_type, index);
+        self.black.clear(index);
+        self.white.clear(index);
+        match piece_type {
+            Piece::BLACK => self.black.set(index),
+            Piece::WHITE => self.white.set(index),
+            Piece::EMPTY => {}
         }
+
+        Ok(piece)
     }
 
     /// Return a list of the possible moves that can be made from a given position on the board.
-    /// An assumption is made that only enemy pieces will be placed adjacent to a piece.
+    /// A piece may only jump over pieces of the opposing colour, and an empty point has no moves.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use konane_engine::{Board, Piece};
+    /// use konane_engine::{Board, Piece, Square};
     ///
     /// let mut board = Board::create_empty();
     ///
-    /// let _ = board.set_piece(0, 0, Piece::BLACK);
-    /// let _ = board.set_piece(0, 1, Piece::WHITE);
-    /// let _ = board.set_piece(0, 2, Piece::BLACK);
-    /// let _ = board.set_piece(0, 4, Piece::BLACK);
+    /// let _ = board.set_piece((0, 0), Piece::BLACK);
+    /// let _ = board.set_piece((0, 1), Piece::WHITE);
+    /// let _ = board.set_piece((0, 2), Piece::BLACK);
+    /// let _ = board.set_piece((0, 4), Piece::BLACK);
+    ///
+    /// // The white piece on b1 can land on d1 or f1
+    /// let possible_moves = board.possible_moves(Square::new(0, 1)).unwrap();
+    /// assert_eq!(possible_moves, vec![Square::new(0, 3), Square::new(0, 5)]);
+    /// assert_eq!(possible_moves[0].to_string(), "d1");
     ///
-    /// let possible_moves = board.possible_moves(0, 1).unwrap();
-    /// assert_eq!(possible_moves, vec![(0, 3), (0, 5)]);
+    /// // Black can't jump over its own piece
+    /// let possible_moves = board.possible_moves(Square::new(0, 0)).unwrap();
+    /// assert_eq!(possible_moves, vec![]);
     /// ```
-    pub fn possible_moves(&self, row: usize, col: usize) -> Option<Vec<(usize, usize)>> {
-        if let None = self.get_piece(row, col) {
-            return None;
-        }
+    pub fn possible_moves(&self, square: impl Into<Square>) -> Result<Vec<Square>, KonaneError> {
+        Ok(self
+            .moves_from(square)?
+            .iter()
+            .map(|possible_move| possible_move.to())
+            .collect())
+    }
+
+    /// Return every move that can be made by the piece at a given position, including each
+    /// stage of a multi-jump as its own move.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, Direction, Move, Piece};
+    ///
+    /// let mut board = Board::create_empty();
+    ///
+    /// let _ = board.set_piece((2, 0), Piece::BLACK);
+    /// let _ = board.set_piece((2, 1), Piece::WHITE);
+    /// let _ = board.set_piece((2, 3), Piece::WHITE);
+    ///
+    /// let moves = board.moves_from((2, 0)).unwrap();
+    /// assert_eq!(
+    ///     moves,
+    ///     vec![
+    ///         Move::new((2, 0), Direction::Right, 1),
+    ///         Move::new((2, 0), Direction::Right, 2),
+    ///     ]
+    /// );
+    /// ```
+    pub fn moves_from(&self, square: impl Into<Square>) -> Result<Vec<Move>, KonaneError> {
+        let square = square.into();
+        self.get_piece(square)?;
+
+        Ok(Direction::ALL
+            .iter()
+            .flat_map(|&direction| self.moves_in_direction(square, direction))
+            .collect())
+    }
+
+    /// Return every legal move for one side across the whole board. Moves start from the
+    /// side's own pieces and only jump over the opponent's pieces. Moves are listed point by
+    /// point, row by row.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, Direction, Move, Piece};
+    ///
+    /// let mut board = Board::create_empty();
+    ///
+    /// let _ = board.set_piece((0, 0), Piece::BLACK);
+    /// let _ = board.set_piece((0, 1), Piece::WHITE);
+    /// let _ = board.set_piece((1, 1), Piece::BLACK);
+    ///
+    /// assert_eq!(
+    ///     board.legal_moves(Piece::BLACK),
+    ///     vec![Move::new((0, 0), Direction::Right, 1)]
+    /// );
+    /// assert_eq!(
+    ///     board.legal_moves(Piece::WHITE),
+    ///     vec![Move::new((0, 1), Direction::Down, 1)]
+    /// );
+    /// assert_eq!(board.legal_moves(Piece::EMPTY), vec![]);
+    /// ```
+    pub fn legal_moves(&self, side: Piece) -> Vec<Move> {
+        let (own, enemy) = match side {
+            Piece::BLACK => (self.black, self.white),
+            Piece::WHITE => (self.white, self.black),
+            Piece::EMPTY => return vec![],
+        };
+        let empty = self.on_board & !(self.black | self.white);
 
         let mut moves = vec![];
 
-        // Vertical
-        for i in [-1, 1] {
-            for jump in (0..).step_by(2) {
-                let from_row = row as isize + i * jump;
+        // Jump every piece at once, keeping only those that land on an empty point, then jump
+        // again from wherever they landed to find the multi-jumps.
+        for direction in Direction::ALL {
+            let mut landed = own;
+
+            for jumps in 1.. {
+                let jumped = self.shift(landed, direction) & enemy;
+                landed = self.shift(jumped, direction) & empty;
 
-                // If moving takes the piece out of bounds, we instantly continue.
-                if from_row + i * 2 < 0 || from_row + i * 2 > 5 {
+                if landed.is_empty() {
                     break;
                 }
 
-                let enemy_row = (from_row + i) as usize;
-                let to_row = (from_row + i * 2) as usize;
+                for index in landed.iter() {
+                    let from = Square::from_index(index, self.width)
+                        .step(direction.opposite(), jumps * 2)
+                        .expect("a landing is always a whole move away from its origin");
 
-                if let Some(enemy) = self.get_piece(enemy_row, col) {
-                    match enemy {
-                        Piece::EMPTY => break,
-                        _ => {
-                            if let Some(Piece::EMPTY) = self.get_piece(to_row, col) {
-                                moves.push((to_row, col));
-                            }
-                        }
-                    }
+                    moves.push(Move::new(from, direction, jumps));
                 }
             }
         }
 
-        // Horizontal
-        for i in [-1, 1] {
-            for jump in (0..).step_by(2) {
-                let from_col = col as isize + i * jump;
+        moves.sort();
+        moves
+    }
 
-                // If moving takes the piece out of bounds, we instantly continue.
-                if from_col + i * 2 < 0 || from_col + i * 2 > 5 {
-                    break;
-                }
+    /// Jump a piece across the board, removing every piece it jumps over. Every jump in the
+    /// move is checked before anything changes, so the board is left untouched if any of them
+    /// is illegal.
+    ///
+    /// Returns the captured pieces, in the order they were jumped. The move is also pushed onto
+    /// the board's undo stack.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, Direction, KonaneError, Move, Piece, Square};
+    ///
+    /// let mut board = Board::create_empty();
+    ///
+    /// let _ = board.set_piece((0, 0), Piece::BLACK);
+    /// let _ = board.set_piece((1, 0), Piece::WHITE);
+    ///
+    /// // There's nothing to jump to the right
+    /// assert_eq!(
+    ///     board.apply_move(Move::new((0, 0), Direction::Right, 1)),
+    ///     Err(KonaneError::NoEnemyToJump(Square::new(0, 1)))
+    /// );
+    ///
+    /// let captured = board.apply_move(Move::new((0, 0), Direction::Down, 1)).unwrap();
+    /// assert_eq!(captured, vec![Piece::WHITE]);
+    ///
+    /// assert_eq!(board.get_piece((0, 0)), Ok(Piece::EMPTY));
+    /// assert_eq!(board.get_piece((1, 0)), Ok(Piece::EMPTY));
+    /// assert_eq!(board.get_piece((2, 0)), Ok(Piece::BLACK));
+    /// ```
+    pub fn apply_move(&mut self, jump: Move) -> Result<Vec<Piece>, KonaneError> {
+        let from = jump.from();
 
-                let enemy_col = (from_col + i) as usize;
-                let to_col = (from_col + i * 2) as usize;
+        let jumper = self.get_piece(from)?;
+        if jumper == Piece::EMPTY {
+            return Err(KonaneError::EmptyPoint(from));
+        }
+        if jump.jumps() == 0 {
+            return Err(KonaneError::NotAJump);
+        }
+        for jump_number in 1..=jump.jumps() {
+            self.check_jump(
+                jump.from(),
+                jump.direction(),
+                jump_number,
+                jumper.opponent(),
+            )?;
+        }
 
-                if let Some(enemy) = self.get_piece(row, enemy_col) {
-                    match enemy {
-                        Piece::EMPTY => break,
-                        _ => {
-                            if let Some(Piece::EMPTY) = self.get_piece(row, to_col) {
-                                moves.push((row, to_col));
-                            }
-                        }
-                    }
-                }
+        self.set_piece(from, Piece::EMPTY)?;
+
+        let captured = jump
+            .captures()
+            .into_iter()
+            .map(|square| self.set_piece(square, Piece::EMPTY))
+            .collect::<Result<Vec<Piece>, KonaneError>>()?;
+
+        self.set_piece(jump.to(), jumper)?;
+
+        self.undo_stack.push(Undo {
+            jump,
+            captured: captured.clone(),
+        });
+
+        Ok(captured)
+    }
+
+    /// Take back the most recently applied move, putting the jumping piece back where it
+    /// started and returning every captured piece to the board. Returns the move that was
+    /// taken back, or [`KonaneError::NothingToUndo`] if there are no moves left to undo.
+    ///
+    /// Points changed with [`Board::set_piece`] since the move was applied are not tracked, so
+    /// the prior position is only restored exactly if those points were left alone.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, Direction, KonaneError, Move, Piece};
+    ///
+    /// let mut board = Board::create_empty();
+    ///
+    /// let _ = board.set_piece((0, 0), Piece::BLACK);
+    /// let _ = board.set_piece((0, 1), Piece::WHITE);
+    /// let _ = board.set_piece((0, 3), Piece::WHITE);
+    ///
+    /// let double_jump = Move::new((0, 0), Direction::Right, 2);
+    /// board.apply_move(double_jump).unwrap();
+    /// assert_eq!(board.get_piece((0, 4)), Ok(Piece::BLACK));
+    ///
+    /// assert_eq!(board.unmake_move(), Ok(double_jump));
+    /// assert_eq!(board.get_piece((0, 0)), Ok(Piece::BLACK));
+    /// assert_eq!(board.get_piece((0, 1)), Ok(Piece::WHITE));
+    /// assert_eq!(board.get_piece((0, 3)), Ok(Piece::WHITE));
+    /// assert_eq!(board.get_piece((0, 4)), Ok(Piece::EMPTY));
+    ///
+    /// assert_eq!(board.unmake_move(), Err(KonaneError::NothingToUndo));
+    /// ```
+    pub fn unmake_move(&mut self) -> Result<Move, KonaneError> {
+        let Undo { jump, captured } = self.undo_stack.pop().ok_or(KonaneError::NothingToUndo)?;
+
+        let jumper = self.set_piece(jump.to(), Piece::EMPTY)?;
+
+        for (square, piece) in jump.captures().into_iter().zip(captured) {
+            self.set_piece(square, piece)?;
+        }
+
+        self.set_piece(jump.from(), jumper)?;
+
+        Ok(jump)
+    }
+
+    /// The moves applied to this board that have not been taken back, oldest first.
+    pub fn applied_moves(&self) -> impl Iterator<Item = Move> + '_ {
+        self.undo_stack.iter().map(|undo| undo.jump)
+    }
+
+    /// A copy of the board's size and pieces without the moves applied to it, for keeping a
+    /// position without paying for its history.
+    pub(crate) fn position(&self) -> Board {
+        Board {
+            undo_stack: vec![],
+            ..*self
+        }
+    }
+
+    /// A 64 bit Zobrist key identifying the board's size and pieces. The key is updated as
+    /// pieces are set and moves are made, so finding it is free, and boards that are equal
+    /// always share a key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, Direction, Move, Piece};
+    ///
+    /// let mut board = Board::create_empty();
+    /// let _ = board.set_piece((0, 0), Piece::BLACK);
+    /// let _ = board.set_piece((0, 1), Piece::WHITE);
+    /// let start = board.zobrist_key();
+    ///
+    /// board.apply_move(Move::new((0, 0), Direction::Right, 1)).unwrap();
+    /// assert_ne!(board.zobrist_key(), start);
+    ///
+    /// board.unmake_move().unwrap();
+    /// assert_eq!(board.zobrist_key(), start);
+    /// ```
+    pub fn zobrist_key(&self) -> u64 {
+        self.key
+    }
+
+    /// The board's Zobrist key with the side to move folded in, so that the same pieces with a
+    /// different player to move get a different key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, Piece};
+    ///
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(board.hash_key(Piece::BLACK), board.zobrist_key());
+    /// assert_ne!(board.hash_key(Piece::WHITE), board.hash_key(Piece::BLACK));
+    /// ```
+    pub fn hash_key(&self, side_to_move: Piece) -> u64 {
+        match side_to_move {
+            Piece::WHITE => self.key ^ zobrist::WHITE_TO_MOVE_KEY,
+            _ => self.key,
+        }
+    }
+
+    /// The number of pieces of one colour on the board.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, Piece};
+    ///
+    /// let board = Board::default();
+    ///
+    /// assert_eq!(board.count(Piece::BLACK), 18);
+    /// assert_eq!(board.count(Piece::WHITE), 18);
+    /// assert_eq!(board.count(Piece::EMPTY), 0);
+    /// ```
+    pub fn count(&self, piece: Piece) -> usize {
+        match piece {
+            Piece::BLACK => self.black.count(),
+            Piece::WHITE => self.white.count(),
+            Piece::EMPTY => (self.on_board & !(self.black | self.white)).count(),
+        }
+    }
+
+    /// The piece on the point with the given index, counting row by row from the top left.
+    fn piece_at(&self, index: usize) -> Piece {
+        if self.black.get(index) {
+            Piece::BLACK
+        } else if self.white.get(index) {
+            Piece::WHITE
+        } else {
+            Piece::EMPTY
+        }
+    }
+
+    /// Move every point in a bitboard one step in a direction, dropping any that would leave
+    /// the board.
+    fn shift(&self, points: Bitboard, direction: Direction) -> Bitboard {
+        match direction {
+            Direction::Up => points.shift_down(self.width),
+            Direction::Down => points.shift_up(self.width) & self.on_board,
+            Direction::Left => (points & self.not_first_col).shift_down(1),
+            Direction::Right => (points & self.not_last_col).shift_up(1),
+        }
+    }
+
+    /// Scan outwards from a position in one direction, returning a move for every point the
+    /// piece could land on. Scanning stops at the first jump that isn't legal.
+    fn moves_in_direction(&self, from: Square, direction: Direction) -> Vec<Move> {
+        let mut moves = vec![];
+
+        let enemy_piece = match self.get_piece(from) {
+            Ok(Piece::EMPTY) | Err(_) => return moves,
+            Ok(jumper) => jumper.opponent(),
+        };
+
+        for jumps in 1.. {
+            if self
+                .check_jump(from, direction, jumps, enemy_piece)
+                .is_err()
+            {
+                break;
             }
+
+            moves.push(Move::new(from, direction, jumps));
+        }
+
+        moves
+    }
+
+    /// Check a single jump along a line of jumps starting at `from`: that the `jump_number`th
+    /// point jumped over holds an enemy piece, and that the point beyond it is empty.
+    fn check_jump(
+        &self,
+        from: Square,
+        direction: Direction,
+        jump_number: usize,
+        enemy_piece: Piece,
+    ) -> Result<(), KonaneError> {
+        let on_board = |distance| {
+            from.step(direction, distance)
+                .filter(|&square| self.contains(square))
+                .ok_or(KonaneError::JumpOffBoard)
+        };
+
+        let enemy = on_board(jump_number * 2 - 1)?;
+        if self.get_piece(enemy)? != enemy_piece {
+            return Err(KonaneError::NoEnemyToJump(enemy));
         }
 
-        Some(moves)
+        let landing = on_board(jump_number * 2)?;
+        if self.get_piece(landing)? != Piece::EMPTY {
+            return Err(KonaneError::LandingOccupied(landing));
+        }
+
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Board, Piece};
+    use crate::{testing::random_board, Board, Direction, KonaneError, Move, Piece, Square};
+
+    const PIECES: [Piece; 3] = [Piece::BLACK, Piece::WHITE, Piece::EMPTY];
 
     #[test]
     fn can_jump_once() {
         let mut board = Board::create_empty();
 
-        let _ = board.set_piece(0, 0, Piece::BLACK);
+        let _ = board.set_piece((0, 0), Piece::BLACK);
 
-        assert_eq!(board.possible_moves(0, 0), Some(vec![]));
+        assert_eq!(board.possible_moves((0, 0)), Ok(vec![]));
 
-        let _ = board.set_piece(3, 3, Piece::BLACK);
-        let _ = board.set_piece(3, 4, Piece::WHITE);
-        let _ = board.set_piece(4, 3, Piece::WHITE);
+        let _ = board.set_piece((3, 3), Piece::BLACK);
+        let _ = board.set_piece((3, 4), Piece::WHITE);
+        let _ = board.set_piece((4, 3), Piece::WHITE);
 
-        let mut possible_moves = board.possible_moves(3, 3).unwrap();
+        let mut possible_moves = board.possible_moves((3, 3)).unwrap();
         possible_moves.sort();
 
-        let mut ideal = vec![(3, 5), (5, 3)];
+        let mut ideal = vec![Square::new(3, 5), Square::new(5, 3)];
         ideal.sort();
 
         assert_eq!(possible_moves, ideal);
     }
+
+    #[test]
+    fn cannot_jump_through_occupied_landing() {
+        let mut board = Board::create_empty();
+
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((0, 1), Piece::WHITE);
+        let _ = board.set_piece((0, 2), Piece::WHITE);
+        let _ = board.set_piece((0, 3), Piece::WHITE);
+
+        assert_eq!(board.possible_moves((0, 0)), Ok(vec![]));
+    }
+
+    #[test]
+    fn apply_multi_jump() {
+        let mut board = Board::create_empty();
+
+        let _ = board.set_piece((5, 0), Piece::WHITE);
+        let _ = board.set_piece((4, 0), Piece::BLACK);
+        let _ = board.set_piece((2, 0), Piece::BLACK);
+
+        let captured = board.apply_move(Move::new((5, 0), Direction::Up, 2));
+        assert_eq!(captured, Ok(vec![Piece::BLACK, Piece::BLACK]));
+
+        for row in 0..6 {
+            let expected = if row == 1 { Piece::WHITE } else { Piece::EMPTY };
+            assert_eq!(board.get_piece((row, 0)), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn rejected_move_leaves_board_untouched() {
+        let mut board = Board::default();
+
+        assert_eq!(
+            board.apply_move(Move::new((0, 0), Direction::Down, 1)),
+            Err(KonaneError::LandingOccupied(Square::new(2, 0)))
+        );
+        assert_eq!(board.to_string(), Board::default().to_string());
+        assert_eq!(board.unmake_move(), Err(KonaneError::NothingToUndo));
+    }
+
+    #[test]
+    fn only_jumps_over_enemy_pieces() {
+        let mut board = Board::create_empty();
+
+        let _ = board.set_piece((2, 2), Piece::BLACK);
+        let _ = board.set_piece((2, 3), Piece::BLACK);
+        let _ = board.set_piece((3, 2), Piece::WHITE);
+        let _ = board.set_piece((5, 2), Piece::BLACK);
+
+        assert_eq!(board.possible_moves((2, 2)), Ok(vec![Square::new(4, 2)]));
+        assert_eq!(board.possible_moves((0, 0)), Ok(vec![]));
+
+        // The second jump down would be over Black's own piece
+        let _ = board.set_piece((5, 2), Piece::EMPTY);
+        let _ = board.set_piece((4, 2), Piece::BLACK);
+        assert_eq!(board.possible_moves((2, 2)), Ok(vec![]));
+    }
+
+    #[test]
+    fn legal_moves_for_each_side() {
+        let mut board = Board::create_empty();
+
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((0, 1), Piece::WHITE);
+        let _ = board.set_piece((0, 3), Piece::WHITE);
+        let _ = board.set_piece((1, 0), Piece::WHITE);
+        let _ = board.set_piece((5, 5), Piece::WHITE);
+
+        assert_eq!(
+            board.legal_moves(Piece::BLACK),
+            vec![
+                Move::new((0, 0), Direction::Down, 1),
+                Move::new((0, 0), Direction::Right, 1),
+                Move::new((0, 0), Direction::Right, 2),
+            ]
+        );
+        assert_eq!(board.legal_moves(Piece::WHITE), vec![]);
+    }
+
+    #[test]
+    fn unmake_restores_every_move_in_reverse() {
+        let mut board = Board::create_empty();
+
+        let _ = board.set_piece((1, 1), Piece::BLACK);
+        let _ = board.set_piece((1, 2), Piece::WHITE);
+        let _ = board.set_piece((1, 4), Piece::WHITE);
+        let _ = board.set_piece((2, 5), Piece::WHITE);
+
+        let start = board.to_string();
+
+        let first = Move::new((1, 1), Direction::Right, 2);
+        let second = Move::new((1, 5), Direction::Down, 1);
+        board.apply_move(first).unwrap();
+        board.apply_move(second).unwrap();
+        let end = board.to_string();
+
+        assert_eq!(
+            board.applied_moves().collect::<Vec<_>>(),
+            vec![first, second]
+        );
+
+        assert_eq!(board.unmake_move(), Ok(second));
+        assert_eq!(board.unmake_move(), Ok(first));
+        assert_eq!(board.to_string(), start);
+
+        board.apply_move(first).unwrap();
+        board.apply_move(second).unwrap();
+        assert_eq!(board.to_string(), end);
+    }
+
+    #[test]
+    fn rectangular_boards() {
+        let mut board = Board::with_size(3, 9).unwrap();
+
+        let _ = board.set_piece((8, 2), Piece::BLACK);
+        let _ = board.set_piece((7, 2), Piece::WHITE);
+        let _ = board.set_piece((5, 2), Piece::WHITE);
+        let _ = board.set_piece((3, 2), Piece::WHITE);
+
+        assert_eq!(
+            board.possible_moves((8, 2)),
+            Ok(vec![
+                Square::new(6, 2),
+                Square::new(4, 2),
+                Square::new(2, 2)
+            ])
+        );
+        assert_eq!(
+            board.set_piece((2, 3), Piece::BLACK),
+            Err(KonaneError::OutOfBounds(Square::new(2, 3)))
+        );
+        assert_eq!(board.to_string().lines().count(), 9);
+        assert!(board.to_string().lines().all(|row| row.len() == 6));
+    }
+
+    #[test]
+    fn long_jumps_on_the_largest_board() {
+        let mut board = Board::with_size(Board::MAX_SIZE, Board::MAX_SIZE).unwrap();
+
+        let _ = board.set_piece((19, 0), Piece::WHITE);
+        for row in (0..19).step_by(2) {
+            let _ = board.set_piece((row, 0), Piece::BLACK);
+        }
+
+        let moves = board.legal_moves(Piece::WHITE);
+        assert_eq!(moves.len(), 9);
+        assert_eq!(moves.last(), Some(&Move::new((19, 0), Direction::Up, 9)));
+    }
+
+    #[test]
+    fn apply_move_explains_rejections() {
+        let mut board = Board::create_empty();
+
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((0, 1), Piece::WHITE);
+        let _ = board.set_piece((0, 3), Piece::BLACK);
+        let _ = board.set_piece((1, 0), Piece::WHITE);
+        let _ = board.set_piece((2, 0), Piece::WHITE);
+
+        let mut rejection = |jump| board.apply_move(jump).unwrap_err();
+
+        assert_eq!(
+            rejection(Move::new((3, 3), Direction::Up, 1)),
+            KonaneError::EmptyPoint(Square::new(3, 3))
+        );
+        assert_eq!(
+            rejection(Move::new((0, 0), Direction::Right, 0)),
+            KonaneError::NotAJump
+        );
+        assert_eq!(
+            rejection(Move::new((0, 0), Direction::Up, 1)),
+            KonaneError::JumpOffBoard
+        );
+        assert_eq!(
+            rejection(Move::new((0, 0), Direction::Down, 1)),
+            KonaneError::LandingOccupied(Square::new(2, 0))
+        );
+        assert_eq!(
+            rejection(Move::new((0, 0), Direction::Right, 2)),
+            KonaneError::NoEnemyToJump(Square::new(0, 3))
+        );
+        assert_eq!(
+            rejection(Move::new((0, 6), Direction::Left, 1)),
+            KonaneError::OutOfBounds(Square::new(0, 6))
+        );
+    }
+
+    #[test]
+    fn bitboard_moves_match_scanning_every_point() {
+        let mut seed = 0x2545_f491_4f6c_dd1d;
+
+        for (width, height) in [(1, 1), (3, 9), (6, 6), (8, 8), (11, 7), (20, 20)] {
+            for _ in 0..50 {
+                let board = random_board(&mut seed, width, height, &PIECES);
+
+                for side in [Piece::BLACK, Piece::WHITE] {
+                    let mut scanned = vec![];
+                    for row in 0..height {
+                        for col in 0..width {
+                            if board.get_piece((row, col)) == Ok(side) {
+                                scanned.extend(board.moves_from((row, col)).unwrap());
+                            }
+                        }
+                    }
+
+                    assert_eq!(board.legal_moves(side), scanned);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn keys_depend_only_on_the_position() {
+        let mut seed = 0x9e37_79b9_7f4a_7c15;
+        let board = random_board(&mut seed, 7, 5, &PIECES);
+
+        // Set the same pieces in the opposite order, over the top of a different position
+        let mut rebuilt = random_board(&mut seed, 7, 5, &PIECES);
+        for row in (0..5).rev() {
+            for col in (0..7).rev() {
+                let _ = rebuilt.set_piece((row, col), board.get_piece((row, col)).unwrap());
+            }
+        }
+
+        assert_eq!(rebuilt, board);
+        assert_eq!(rebuilt.zobrist_key(), board.zobrist_key());
+        assert_ne!(
+            Board::with_size(7, 5).unwrap().zobrist_key(),
+            Board::with_size(5, 7).unwrap().zobrist_key()
+        );
+    }
+
+    #[test]
+    fn keys_survive_make_and_unmake() {
+        let mut seed = 0x2545_f491_4f6c_dd1d;
+
+        for _ in 0..20 {
+            let mut board = random_board(&mut seed, 8, 8, &PIECES);
+            let start = board.zobrist_key();
+
+            for jump in board.legal_moves(Piece::BLACK) {
+                board.apply_move(jump).unwrap();
+
+                let mut fresh = Board::with_size(8, 8).unwrap();
+                for row in 0..8 {
+                    for col in 0..8 {
+                        let _ = fresh.set_piece((row, col), board.get_piece((row, col)).unwrap());
+                    }
+                }
+                assert_eq!(board.zobrist_key(), fresh.zobrist_key());
+
+                board.unmake_move().unwrap();
+                assert_eq!(board.zobrist_key(), start);
+            }
+        }
+    }
 }
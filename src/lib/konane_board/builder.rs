@@ -0,0 +1,263 @@
+use super::{Board, Piece, Square};
+use crate::KonaneError;
+
+/// A starting arrangement of pieces for a [`Board`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Setup {
+    /// A full checkerboard with Black in the top left corner.
+    #[default]
+    Standard,
+    /// A full checkerboard with White in the top left corner.
+    Swapped,
+    /// A custom arrangement, written one row per line with `B` for black, `W` for white and
+    /// `.` for an empty point. Spaces between points and blank lines are ignored.
+    Pattern(String),
+}
+
+/// Builds a [`Board`] of a given size from a named [`Setup`].
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{BoardBuilder, Piece, Setup};
+///
+/// let swapped = BoardBuilder::new().setup(Setup::Swapped).build().unwrap();
+/// assert_eq!(swapped.get_piece((0, 0)), Ok(Piece::WHITE));
+/// assert_eq!(swapped.get_piece((0, 1)), Ok(Piece::BLACK));
+///
+/// let pattern = "
+///     B W . . . .
+///     . . . . . .
+///     . . . . . .
+///     . . . . . .
+///     . . . . . .
+///     . . . . . W
+/// ";
+/// let custom = BoardBuilder::new()
+///     .setup(Setup::Pattern(pattern.to_string()))
+///     .build()
+///     .unwrap();
+/// assert_eq!(custom.get_piece((0, 1)), Ok(Piece::WHITE));
+/// assert_eq!(custom.get_piece((5, 5)), Ok(Piece::WHITE));
+/// assert_eq!(custom.get_piece((2, 2)), Ok(Piece::EMPTY));
+///
+/// let large = BoardBuilder::new().size(18, 18).build().unwrap();
+/// assert_eq!(large.get_piece((17, 17)), Ok(Piece::BLACK));
+/// ```
+#[derive(Clone, Debug)]
+pub struct BoardBuilder {
+    width: usize,
+    height: usize,
+    setup: Setup,
+}
+
+impl Default for BoardBuilder {
+    fn default() -> Self {
+        BoardBuilder {
+            width: 6,
+            height: 6,
+            setup: Setup::Standard,
+        }
+    }
+}
+
+impl BoardBuilder {
+    /// Start building a 6x6 board with the standard setup.
+    pub fn new() -> BoardBuilder {
+        BoardBuilder::default()
+    }
+
+    /// Choose the number of columns and rows on the board.
+    pub fn size(mut self, width: usize, height: usize) -> BoardBuilder {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Choose the arrangement of pieces to start from.
+    pub fn setup(mut self, setup: Setup) -> BoardBuilder {
+        self.setup = setup;
+        self
+    }
+
+    /// Build the board. Fails if the size is invalid or a pattern doesn't describe every point
+    /// on the board.
+    pub fn build(&self) -> Result<Board, KonaneError> {
+        let mut board = Board::with_size(self.width, self.height)?;
+
+        match &self.setup {
+            Setup::Standard => fill_checkerboard(&mut board, Piece::BLACK),
+            Setup::Swapped => fill_checkerboard(&mut board, Piece::WHITE),
+            Setup::Pattern(pattern) => fill_pattern(&mut board, pattern)?,
+        }
+
+        Ok(board)
+    }
+}
+
+/// Fill every point of the board, alternating colours along both rows and columns.
+fn fill_checkerboard(board: &mut Board, top_left: Piece) {
+    for row in 0..board.height() {
+        for col in 0..board.width() {
+            let piece = if (row + col) % 2 == 0 {
+                top_left
+            } else {
+                top_left.opponent()
+            };
+
+            let _ = board.set_piece((row, col), piece);
+        }
+    }
+}
+
+fn fill_pattern(board: &mut Board, pattern: &str) -> Result<(), KonaneError> {
+    let rows = pattern
+        .lines()
+        .map(|line| {
+            line.chars()
+                .filter(|c| !c.is_whitespace())
+                .collect::<Vec<_>>()
+        })
+        .filter(|row| !row.is_empty())
+        .collect::<Vec<_>>();
+
+    if rows.len() != board.height() {
+        return Err(KonaneError::InvalidPattern(format!(
+            "expected {} rows but found {}",
+            board.height(),
+            rows.len()
+        )));
+    }
+
+    for (row, points) in rows.iter().enumerate() {
+        if points.len() != board.width() {
+            return Err(KonaneError::InvalidPattern(format!(
+                "expected {} points in row {} but found {}",
+                board.width(),
+                row + 1,
+                points.len()
+            )));
+        }
+
+        for (col, point) in points.iter().enumerate() {
+            let piece = match point {
+                'B' => Piece::BLACK,
+                'W' => Piece::WHITE,
+                '.' => Piece::EMPTY,
+                _ => {
+                    return Err(KonaneError::InvalidPattern(format!(
+                        "unknown point '{}' at {}",
+                        point,
+                        Square::new(row, col)
+                    )))
+                }
+            };
+
+            board.set_piece((row, col), piece)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Board, BoardBuilder, KonaneError, Piece, Setup};
+
+    #[test]
+    fn standard_setup_is_a_checkerboard() {
+        let board = BoardBuilder::new().build().unwrap();
+
+        for row in 0..6 {
+            for col in 0..6 {
+                let piece = board.get_piece((row, col)).unwrap();
+
+                assert_ne!(piece, Piece::EMPTY);
+                if col < 5 {
+                    assert_eq!(board.get_piece((row, col + 1)), Ok(piece.opponent()));
+                }
+                if row < 5 {
+                    assert_eq!(board.get_piece((row + 1, col)), Ok(piece.opponent()));
+                }
+            }
+        }
+
+        assert_eq!(board.to_string(), Board::default().to_string());
+    }
+
+    #[test]
+    fn swapped_setup_is_the_opposite_colours() {
+        let standard = BoardBuilder::new().setup(Setup::Standard).build().unwrap();
+        let swapped = BoardBuilder::new().setup(Setup::Swapped).build().unwrap();
+
+        for row in 0..6 {
+            for col in 0..6 {
+                assert_eq!(
+                    swapped.get_piece((row, col)),
+                    standard.get_piece((row, col)).map(Piece::opponent)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn pattern_must_fill_the_board() {
+        let build = |pattern: &str| {
+            BoardBuilder::new()
+                .setup(Setup::Pattern(pattern.to_string()))
+                .build()
+        };
+
+        assert!(build("BWBWBW\n".repeat(6).as_str()).is_ok());
+        assert_eq!(
+            build("BWBWBW\n".repeat(5).as_str()).unwrap_err(),
+            KonaneError::InvalidPattern("expected 6 rows but found 5".to_string())
+        );
+        assert_eq!(
+            build("BWBWB\n".repeat(6).as_str()).unwrap_err(),
+            KonaneError::InvalidPattern("expected 6 points in row 1 but found 5".to_string())
+        );
+        let short_third = "BWBWBW\n".repeat(2) + "BWBWB\n" + &"BWBWBW\n".repeat(3);
+        assert_eq!(
+            build(&short_third).unwrap_err(),
+            KonaneError::InvalidPattern("expected 6 points in row 3 but found 5".to_string())
+        );
+        assert_eq!(
+            build("BWBWBX\n".repeat(6).as_str()).unwrap_err(),
+            KonaneError::InvalidPattern("unknown point 'X' at f1".to_string())
+        );
+    }
+
+    #[test]
+    fn pattern_must_match_the_size() {
+        let build = |width: usize, height: usize| {
+            BoardBuilder::new()
+                .size(width, height)
+                .setup(Setup::Pattern("B.W\n.W.\n".to_string()))
+                .build()
+        };
+
+        assert!(build(6, 6).is_err());
+        assert!(build(2, 3).is_err());
+
+        let board = build(3, 2).unwrap();
+        assert_eq!(board.get_piece((1, 1)), Ok(Piece::WHITE));
+    }
+
+    #[test]
+    fn odd_sized_checkerboards() {
+        let board = BoardBuilder::new().size(5, 7).build().unwrap();
+
+        assert_eq!(board.get_piece((0, 4)), Ok(Piece::BLACK));
+        assert_eq!(board.get_piece((6, 0)), Ok(Piece::BLACK));
+        assert_eq!(board.get_piece((6, 4)), Ok(Piece::BLACK));
+        assert_eq!(board.get_piece((3, 2)), Ok(Piece::WHITE));
+        assert_eq!(
+            BoardBuilder::new().size(0, 7).build().unwrap_err(),
+            KonaneError::InvalidSize {
+                width: 0,
+                height: 7
+            }
+        );
+    }
+}
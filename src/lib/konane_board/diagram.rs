@@ -0,0 +1,342 @@
+use super::{Board, Piece};
+use crate::KonaneError;
+
+impl Board {
+    /// Read a board drawn as a grid, such as one printed with `Display`.
+    ///
+    /// Each line is a row, from the top down, with `B` for black and `W` for white. Empty points
+    /// are either spaces, as `Display` prints them, or `.`:
+    ///
+    /// - Without any `.`, points are a character each with a space between them, just as
+    ///   `Display` prints them, and rows that stop short are filled out with empty points, so
+    ///   trailing spaces can be trimmed.
+    /// - With a `.` anywhere, every character other than whitespace is a point, however the
+    ///   points are spaced, and every row must have the same number of them.
+    ///
+    /// The diagram may also be labelled like a [square](crate::Square). A line of column
+    /// letters, `a b c ...`, may go above or below the grid, which also fixes its width, and
+    /// each row may start with its number followed by a single space. Empty lines before and
+    /// after the grid are skipped, but an empty row needs its spaces, or dots, to be kept.
+    ///
+    /// Errors point to the line and column of the diagram, counting from one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, KonaneError, Piece};
+    ///
+    /// let board = Board::default();
+    /// assert_eq!(Board::from_diagram(&board.to_string()), Ok(board));
+    ///
+    /// let labelled = "
+    ///   a b c d
+    /// 1 B W . .
+    /// 2 . . . W
+    /// ";
+    /// let board = Board::from_diagram(labelled).unwrap();
+    /// assert_eq!((board.width(), board.height()), (4, 2));
+    /// assert_eq!(board.get_piece((1, 3)), Ok(Piece::WHITE));
+    ///
+    /// assert_eq!(
+    ///     Board::from_diagram("B W\nW X"),
+    ///     Err(KonaneError::InvalidDiagram {
+    ///         line: 2,
+    ///         column: 3,
+    ///         reason: "unknown point 'X'".to_string()
+    ///     })
+    /// );
+    /// ```
+    pub fn from_diagram(diagram: &str) -> Result<Board, KonaneError> {
+        let mut lines = diagram
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line))
+            .skip_while(|(_, line)| line.is_empty())
+            .collect::<Vec<_>>();
+        while lines.last().is_some_and(|(_, line)| line.is_empty()) {
+            lines.pop();
+        }
+
+        let header = lines.first().and_then(|&(_, line)| column_labels(line));
+        if header.is_some() {
+            lines.remove(0);
+        }
+        let footer = lines.last().and_then(|&(_, line)| column_labels(line));
+        if let Some(&(line, _)) = lines.last().filter(|_| footer.is_some()) {
+            if header.is_some_and(|header| Some(header) != footer) {
+                return Err(KonaneError::InvalidDiagram {
+                    line,
+                    column: 1,
+                    reason: "the column labels above and below the board don't match".to_string(),
+                });
+            }
+            lines.pop();
+        }
+
+        let rows = lines
+            .iter()
+            .enumerate()
+            .map(|(index, &(line, text))| Row::read(index, line, text))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let dotted = rows.iter().any(|row| row.body.contains('.'));
+        let points = rows
+            .iter()
+            .map(|row| row.points(dotted))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let width = header
+            .or(footer)
+            .unwrap_or_else(|| points.iter().map(|points| points.len()).max().unwrap_or(0));
+        let mut board = Board::with_size(width, rows.len())?;
+
+        for (row_index, (row, points)) in rows.iter().zip(&points).enumerate() {
+            if let Some(&(_, column)) = points.get(width) {
+                return Err(row.error(column, "the row has more points than there are columns"));
+            }
+            if dotted && points.len() < width {
+                let end = row.offset + row.body.chars().count() + 1;
+                let reason = format!("expected {} points but found {}", width, points.len());
+                return Err(row.error(end, &reason));
+            }
+
+            for (col, &(piece, _)) in points.iter().enumerate() {
+                board.set_piece((row_index, col), piece)?;
+            }
+        }
+
+        Ok(board)
+    }
+}
+
+/// A line of the diagram holding a row of the board.
+struct Row<'a> {
+    line: usize,
+    /// How many characters come before the points, such as the row's label.
+    offset: usize,
+    /// The points, without the row's label.
+    body: &'a str,
+}
+
+impl<'a> Row<'a> {
+    /// Take the row numbered `index` from zero from `text`, checking its label if it has one.
+    fn read(index: usize, line: usize, text: &'a str) -> Result<Row<'a>, KonaneError> {
+        let trimmed = text.trim_start();
+        let mut row = Row {
+            line,
+            offset: 0,
+            body: text,
+        };
+
+        if !trimmed.starts_with(|char: char| char.is_ascii_digit()) {
+            return Ok(row);
+        }
+
+        let indent = text.chars().count() - trimmed.chars().count();
+        let digits = trimmed.bytes().take_while(u8::is_ascii_digit).count();
+        let label = &trimmed[..digits];
+        if label.parse() != Ok(index + 1) {
+            let reason = format!("expected row {} but found row {}", index + 1, label);
+            return Err(row.error(indent + 1, &reason));
+        }
+
+        let rest = &trimmed[digits..];
+        row.offset = indent + digits;
+        row.body = match rest.strip_prefix(' ') {
+            Some(body) => {
+                row.offset += 1;
+                body
+            }
+            None if rest.is_empty() => rest,
+            None => return Err(row.error(row.offset + 1, "expected a space after the row number")),
+        };
+
+        Ok(row)
+    }
+
+    /// Every point in the row along with its column in the diagram.
+    fn points(&self, dotted: bool) -> Result<Vec<(Piece, usize)>, KonaneError> {
+        let mut points = vec![];
+
+        for (index, char) in self.body.chars().enumerate() {
+            let column = self.offset + index + 1;
+
+            let piece = match char {
+                'B' => Piece::BLACK,
+                'W' => Piece::WHITE,
+                '.' => Piece::EMPTY,
+                ' ' if !dotted && index % 2 == 0 => Piece::EMPTY,
+                _ if char.is_whitespace() => continue,
+                _ => return Err(self.error(column, &format!("unknown point '{}'", char))),
+            };
+
+            if !dotted && index % 2 == 1 {
+                return Err(self.error(column, "expected a space between points"));
+            }
+            points.push((piece, column));
+        }
+
+        Ok(points)
+    }
+
+    fn error(&self, column: usize, reason: &str) -> KonaneError {
+        KonaneError::InvalidDiagram {
+            line: self.line,
+            column,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// The number of columns, if `line` labels them `a b c ...`.
+fn column_labels(line: &str) -> Option<usize> {
+    let labels = line.split_whitespace().collect::<Vec<_>>();
+    let in_order = labels.iter().zip('a'..='z').all(|(label, letter)| {
+        let mut chars = label.chars();
+        chars.next() == Some(letter) && chars.next().is_none()
+    });
+
+    (!labels.is_empty() && labels.len() <= 26 && in_order).then_some(labels.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Board, BoardBuilder, KonaneError, Piece, Setup, Square};
+
+    fn error(diagram: &str) -> (usize, usize, String) {
+        match Board::from_diagram(diagram) {
+            Err(KonaneError::InvalidDiagram {
+                line,
+                column,
+                reason,
+            }) => (line, column, reason),
+            other => panic!("expected an invalid diagram but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn reads_what_display_prints() {
+        for (width, height) in [(1, 1), (4, 4), (6, 6), (9, 5), (20, 20)] {
+            let mut board = BoardBuilder::new()
+                .size(width, height)
+                .setup(Setup::Standard)
+                .build()
+                .unwrap();
+            let squares = board.squares().collect::<Vec<_>>();
+            for square in squares {
+                if square.row() == 0 || square.col() + 1 == width || square.index(width) % 3 == 0 {
+                    let _ = board.set_piece(square, Piece::EMPTY);
+                }
+            }
+
+            let diagram = board.to_string();
+            assert_eq!(Board::from_diagram(&diagram), Ok(board.clone()));
+
+            let dotted = diagram.replace("  ", ". ");
+            assert_eq!(Board::from_diagram(&dotted), Ok(board));
+        }
+    }
+
+    #[test]
+    fn keeps_an_empty_left_column() {
+        let mut board = Board::with_size(3, 2).unwrap();
+        let _ = board.set_piece((0, 1), Piece::BLACK);
+        let _ = board.set_piece((1, 2), Piece::WHITE);
+        let diagram = board.to_string();
+        assert_eq!(diagram, "  B   \n    W \n");
+
+        assert_eq!(Board::from_diagram(&diagram), Ok(board.clone()));
+        assert_eq!(
+            Board::from_diagram(&diagram.replace("  ", ". ")),
+            Ok(board.clone())
+        );
+        assert_eq!(Board::from_diagram("1   B\n2     W"), Ok(board));
+
+        let mut board = Board::with_size(6, 6).unwrap();
+        let _ = board.set_piece("d3".parse::<Square>().unwrap(), Piece::BLACK);
+        let _ = board.set_piece("e3".parse::<Square>().unwrap(), Piece::WHITE);
+        assert_eq!(Board::from_diagram(&board.to_string()), Ok(board));
+
+        // Leading spaces are always empty points, so a diagram can't be shifted over by one
+        assert_eq!(
+            error(" B W"),
+            (1, 2, "expected a space between points".to_string())
+        );
+    }
+
+    #[test]
+    fn tolerates_trimmed_and_labelled_diagrams() {
+        let mut board = Board::with_size(4, 3).unwrap();
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((1, 1), Piece::WHITE);
+        let _ = board.set_piece((2, 0), Piece::WHITE);
+
+        for diagram in [
+            "B\n  W\nW      \n  a b c d",
+            "\n\n  a b c d\n1 B\n2   W\n3 W\n",
+            "B...\n.W..\nW...",
+            "   a b c d\n 1 B . . .\n 2 . W . .\n 3 W . . .\n   a b c d",
+            "B . . .\r\n. W . .\r\nW . . .\r\n",
+        ] {
+            assert_eq!(
+                Board::from_diagram(diagram),
+                Ok(board.clone()),
+                "{}",
+                diagram
+            );
+        }
+
+        let wide = "   a b c d e f g h i j k\n10 B . . . . . . . . . .";
+        assert_eq!(
+            error(wide),
+            (2, 1, "expected row 1 but found row 10".to_string())
+        );
+    }
+
+    #[test]
+    fn points_to_mistakes() {
+        assert_eq!(
+            error("B W\nWB"),
+            (2, 2, "expected a space between points".to_string())
+        );
+        assert_eq!(
+            error("B . W\nW B x"),
+            (2, 5, "unknown point 'x'".to_string())
+        );
+        assert_eq!(
+            error("B . W\nW B"),
+            (2, 4, "expected 3 points but found 2".to_string())
+        );
+        assert_eq!(
+            error("  a b\nB W B"),
+            (
+                2,
+                5,
+                "the row has more points than there are columns".to_string()
+            )
+        );
+        assert_eq!(
+            error("1 B W\n3 W B"),
+            (2, 1, "expected row 2 but found row 3".to_string())
+        );
+        assert_eq!(
+            error("1B W"),
+            (1, 2, "expected a space after the row number".to_string())
+        );
+        assert_eq!(
+            error("a b\nB W\na b c"),
+            (
+                3,
+                1,
+                "the column labels above and below the board don't match".to_string()
+            )
+        );
+        assert_eq!(
+            Board::from_diagram("\n\n"),
+            Err(KonaneError::InvalidSize {
+                width: 0,
+                height: 0
+            })
+        );
+    }
+}
@@ -1,5 +1,18 @@
+mod bitboard;
 mod board;
+mod builder;
+mod diagram;
+mod moves;
+mod notation;
 mod point;
+mod regions;
+#[cfg(feature = "serde")]
+mod serialize;
+mod square;
+mod zobrist;
 
 pub use board::Board;
+pub use builder::{BoardBuilder, Setup};
+pub use moves::{Direction, Move};
 pub use point::Piece;
+pub use square::Square;
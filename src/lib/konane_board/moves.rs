@@ -0,0 +1,214 @@
+use std::{fmt::Display, str::FromStr};
+
+use super::Square;
+use crate::KonaneError;
+
+/// One of the four orthogonal directions a piece can jump in.
+/// Rows grow downwards and columns grow to the right.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// Every direction, in the order moves are generated.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// The `(row, col)` offset of a single step in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+        }
+    }
+
+    /// The direction pointing the other way.
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+/// A single turn's jump: a piece leaves `from` and jumps `jumps` times in a straight line,
+/// capturing the piece it passes over on each jump.
+///
+/// Moves are written as every square the piece stops on, joined by dashes, such as
+/// `a1-c1-e1`.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{Direction, Move, Square};
+///
+/// let double_jump = Move::new((0, 0), Direction::Right, 2);
+///
+/// assert_eq!(double_jump.to(), Square::new(0, 4));
+/// assert_eq!(double_jump.landings(), vec![Square::new(0, 2), Square::new(0, 4)]);
+/// assert_eq!(double_jump.captures(), vec![Square::new(0, 1), Square::new(0, 3)]);
+/// assert_eq!(double_jump.to_string(), "a1-c1-e1");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Move {
+    from: Square,
+    direction: Direction,
+    jumps: usize,
+}
+
+impl Move {
+    /// Create a move of the piece at `from`, jumping `jumps` times in `direction`.
+    pub fn new(from: impl Into<Square>, direction: Direction, jumps: usize) -> Move {
+        Move {
+            from: from.into(),
+            direction,
+            jumps,
+        }
+    }
+
+    /// The point the jumping piece starts on.
+    pub fn from(&self) -> Square {
+        self.from
+    }
+
+    /// The direction the piece jumps in.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// The number of pieces jumped over, and therefore captured.
+    pub fn jumps(&self) -> usize {
+        self.jumps
+    }
+
+    /// The point the jumping piece finishes on.
+    pub fn to(&self) -> Square {
+        self.landings().last().copied().unwrap_or(self.from)
+    }
+
+    /// Every point the piece lands on, in order, ending with the final destination.
+    pub fn landings(&self) -> Vec<Square> {
+        (1..=self.jumps)
+            .map_while(|jump| self.from.step(self.direction, jump * 2))
+            .collect()
+    }
+
+    /// Every point jumped over, in order. The pieces on these points are captured.
+    pub fn captures(&self) -> Vec<Square> {
+        (1..=self.jumps)
+            .map_while(|jump| self.from.step(self.direction, jump * 2 - 1))
+            .collect()
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.from)?;
+        for landing in self.landings() {
+            write!(f, "-{}", landing)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads a move written as every square the piece stops on, such as `a1-c1-e1`.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{Direction, KonaneError, Move};
+///
+/// assert_eq!("c3-c5-c7".parse(), Ok(Move::new((2, 2), Direction::Down, 2)));
+/// assert_eq!(
+///     "c3-c5-e5".parse::<Move>(),
+///     Err(KonaneError::InvalidNotation(
+///         "'c3-c5-e5' isn't a straight line of jumps".to_string()
+///     ))
+/// );
+/// ```
+impl FromStr for Move {
+    type Err = KonaneError;
+
+    fn from_str(text: &str) -> Result<Move, KonaneError> {
+        let squares = text
+            .split('-')
+            .map(str::parse::<Square>)
+            .collect::<Result<Vec<_>, _>>()?;
+        let invalid =
+            || KonaneError::InvalidNotation(format!("'{}' isn't a straight line of jumps", text));
+
+        let (&from, landings) = squares.split_first().ok_or_else(invalid)?;
+        let direction = landings
+            .first()
+            .and_then(|&landing| from.direction_to(landing))
+            .ok_or_else(invalid)?;
+
+        let jump = Move::new(from, direction, landings.len());
+        if jump.landings() != landings {
+            return Err(invalid());
+        }
+
+        Ok(jump)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Direction, Move, Square};
+
+    #[test]
+    fn landings_and_captures_alternate() {
+        let double_jump = Move::new((5, 1), Direction::Up, 2);
+
+        assert_eq!(
+            double_jump.captures(),
+            vec![Square::new(4, 1), Square::new(2, 1)]
+        );
+        assert_eq!(
+            double_jump.landings(),
+            vec![Square::new(3, 1), Square::new(1, 1)]
+        );
+        assert_eq!(double_jump.to(), Square::new(1, 1));
+        assert_eq!(double_jump.to_string(), "b6-b4-b2");
+    }
+
+    #[test]
+    fn reads_what_it_writes() {
+        for direction in Direction::ALL {
+            for jumps in 1..=3 {
+                let jump = Move::new((6, 6), direction, jumps);
+                assert_eq!(jump.to_string().parse(), Ok(jump));
+            }
+        }
+
+        for text in ["c3", "c3-c4", "c3-c5-c5", "c3-e5", "c3-c5-c3", "c3-"] {
+            assert!(text.parse::<Move>().is_err());
+        }
+    }
+
+    #[test]
+    fn stops_at_top_left_edge() {
+        let off_board = Move::new((0, 1), Direction::Left, 1);
+
+        assert_eq!(off_board.captures(), vec![Square::new(0, 0)]);
+        assert_eq!(off_board.landings(), vec![]);
+    }
+}
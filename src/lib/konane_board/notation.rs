@@ -0,0 +1,256 @@
+use std::str::FromStr;
+
+use super::{Board, Piece};
+use crate::KonaneError;
+
+impl Board {
+    /// Write the board as a single line of text, which [`Board::from_str`] reads back into an
+    /// equal board.
+    ///
+    /// The notation is the board's width and height, such as `6x6`, then a space, then each
+    /// row from the top down, separated by `/`. Each row lists its points from left to right,
+    /// with `B` for black, `W` for white, and a number for a run of empty points. Numbers never
+    /// start with `0`, so each board has just the one notation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, Piece};
+    ///
+    /// let mut board = Board::with_size(4, 3).unwrap();
+    /// let _ = board.set_piece((0, 0), Piece::BLACK);
+    /// let _ = board.set_piece((0, 1), Piece::WHITE);
+    /// let _ = board.set_piece((2, 3), Piece::WHITE);
+    ///
+    /// assert_eq!(board.to_notation(), "4x3 BW2/4/3W");
+    /// assert_eq!(board.to_notation().parse::<Board>(), Ok(board));
+    /// ```
+    pub fn to_notation(&self) -> String {
+        let rows = (0..self.height())
+            .map(|row| {
+                let mut text = String::new();
+                let mut empty = 0;
+
+                for col in 0..self.width() {
+                    match self.get_piece((row, col)) {
+                        Ok(Piece::BLACK) | Ok(Piece::WHITE) if empty > 0 => {
+                            text.push_str(&empty.to_string());
+                            empty = 0;
+                        }
+                        _ => {}
+                    }
+
+                    match self.get_piece((row, col)) {
+                        Ok(Piece::BLACK) => text.push('B'),
+                        Ok(Piece::WHITE) => text.push('W'),
+                        _ => empty += 1,
+                    }
+                }
+
+                if empty > 0 {
+                    text.push_str(&empty.to_string());
+                }
+                text
+            })
+            .collect::<Vec<_>>();
+
+        format!("{}x{} {}", self.width(), self.height(), rows.join("/"))
+    }
+}
+
+/// Reads a board written by [`Board::to_notation`].
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{Board, KonaneError, Piece};
+///
+/// let board = "3x2 B1W/3".parse::<Board>().unwrap();
+/// assert_eq!(board.get_piece((0, 2)), Ok(Piece::WHITE));
+///
+/// assert_eq!(
+///     "3x2 B1W".parse::<Board>(),
+///     Err(KonaneError::InvalidNotation("expected 2 rows but found 1".to_string()))
+/// );
+/// ```
+impl FromStr for Board {
+    type Err = KonaneError;
+
+    fn from_str(notation: &str) -> Result<Board, KonaneError> {
+        let invalid = |reason: String| KonaneError::InvalidNotation(reason);
+
+        let mut fields = notation.split_whitespace();
+        let (Some(size), Some(rows), None) = (fields.next(), fields.next(), fields.next()) else {
+            return Err(invalid(
+                "expected a size and rows separated by a space".to_string(),
+            ));
+        };
+
+        // Only plain digits, without leading zeros, so that every size is written one way
+        let number = |text: &str| {
+            let canonical = text.bytes().all(|byte| byte.is_ascii_digit())
+                && (text == "0" || !text.starts_with('0'));
+            canonical.then(|| text.parse::<usize>().ok()).flatten()
+        };
+        let (width, height) = size
+            .split_once('x')
+            .and_then(|(width, height)| Some((number(width)?, number(height)?)))
+            .ok_or_else(|| invalid(format!("expected a size like 6x6 but found '{}'", size)))?;
+        let mut board = Board::with_size(width, height)?;
+
+        let rows = rows.split('/').collect::<Vec<_>>();
+        if rows.len() != height {
+            return Err(invalid(format!(
+                "expected {} rows but found {}",
+                height,
+                rows.len()
+            )));
+        }
+
+        for (row, text) in rows.into_iter().enumerate() {
+            let mut col = 0;
+            let mut run = 0;
+
+            let too_long = || {
+                invalid(format!(
+                    "the run of empty points in row {} is too long",
+                    row + 1
+                ))
+            };
+
+            for point in text.chars() {
+                if let Some(digit) = point.to_digit(10) {
+                    if run == 0 && digit == 0 {
+                        return Err(invalid(format!(
+                            "a run of empty points in row {} starts with 0",
+                            row + 1
+                        )));
+                    }
+                    run = usize::checked_mul(run, 10)
+                        .and_then(|run| run.checked_add(digit as usize))
+                        .ok_or_else(too_long)?;
+                    continue;
+                }
+
+                col = usize::checked_add(col, run).ok_or_else(too_long)?;
+                run = 0;
+
+                let piece = match point {
+                    'B' => Piece::BLACK,
+                    'W' => Piece::WHITE,
+                    _ => {
+                        return Err(invalid(format!(
+                            "unknown point '{}' in row {}",
+                            point,
+                            row + 1
+                        )))
+                    }
+                };
+                if col < width {
+                    board.set_piece((row, col), piece)?;
+                }
+                col += 1;
+            }
+            col = usize::checked_add(col, run).ok_or_else(too_long)?;
+
+            if col != width {
+                return Err(invalid(format!(
+                    "expected {} points in row {} but found {}",
+                    width,
+                    row + 1,
+                    col
+                )));
+            }
+        }
+
+        Ok(board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{testing::random_board, Board, BoardBuilder, KonaneError, Piece};
+
+    #[test]
+    fn standard_board_notation() {
+        let board = BoardBuilder::new().size(6, 4).build().unwrap();
+
+        assert_eq!(board.to_notation(), "6x4 BWBWBW/WBWBWB/BWBWBW/WBWBWB");
+        assert_eq!(Board::with_size(12, 1).unwrap().to_notation(), "12x1 12");
+    }
+
+    #[test]
+    fn round_trips() {
+        let mut seed = 0x1234_5678_9abc_def1_u64;
+
+        for (width, height) in [(1, 1), (5, 3), (6, 6), (13, 9), (20, 20)] {
+            for _ in 0..20 {
+                let pieces = [Piece::BLACK, Piece::WHITE, Piece::EMPTY, Piece::EMPTY];
+                let board = random_board(&mut seed, width, height, &pieces);
+
+                let notation = board.to_notation();
+                let parsed = notation.parse::<Board>().unwrap();
+                assert_eq!(parsed, board);
+                assert_eq!(parsed.to_notation(), notation);
+                assert_eq!(parsed.zobrist_key(), board.zobrist_key());
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_bad_notation() {
+        let error = |notation: &str| notation.parse::<Board>().unwrap_err();
+        let invalid = |reason: &str| KonaneError::InvalidNotation(reason.to_string());
+
+        assert_eq!(
+            error("4x1"),
+            invalid("expected a size and rows separated by a space")
+        );
+        assert_eq!(
+            error("four 4"),
+            invalid("expected a size like 6x6 but found 'four'")
+        );
+        assert_eq!(
+            error("0x1 0"),
+            KonaneError::InvalidSize {
+                width: 0,
+                height: 1
+            }
+        );
+        assert_eq!(
+            error("4x2 BW2/5"),
+            invalid("expected 4 points in row 2 but found 5")
+        );
+        assert_eq!(
+            error("4x2 BW2/BWB"),
+            invalid("expected 4 points in row 2 but found 3")
+        );
+        assert_eq!(error("2x1 Bx"), invalid("unknown point 'x' in row 1"));
+        assert_eq!(
+            error("4x1 99999999999999999999B"),
+            invalid("the run of empty points in row 1 is too long")
+        );
+        assert_eq!(
+            error(&format!("4x1 B{}", usize::MAX)),
+            invalid("the run of empty points in row 1 is too long")
+        );
+
+        // Every board has only one notation
+        assert_eq!(
+            error("2x2 BW/0B0W"),
+            invalid("a run of empty points in row 2 starts with 0")
+        );
+        assert_eq!(
+            error("6x1 06"),
+            invalid("a run of empty points in row 1 starts with 0")
+        );
+        assert_eq!(
+            error("06x1 6"),
+            invalid("expected a size like 6x6 but found '06x1'")
+        );
+        assert_eq!(
+            error("+2x1 BW"),
+            invalid("expected a size like 6x6 but found '+2x1'")
+        );
+    }
+}
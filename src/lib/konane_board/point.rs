@@ -1,13 +1,34 @@
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum Piece {
+    #[default]
     EMPTY,
     WHITE,
     BLACK,
 }
 
-impl Default for Piece {
-    fn default() -> Self {
-        Piece::EMPTY
+impl Piece {
+    /// The piece belonging to the other player. Empty points have no opponent.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::Piece;
+    ///
+    /// assert_eq!(Piece::BLACK.opponent(), Piece::WHITE);
+    /// assert_eq!(Piece::WHITE.opponent(), Piece::BLACK);
+    /// assert_eq!(Piece::EMPTY.opponent(), Piece::EMPTY);
+    /// ```
+    pub fn opponent(self) -> Piece {
+        match self {
+            Piece::BLACK => Piece::WHITE,
+            Piece::WHITE => Piece::BLACK,
+            Piece::EMPTY => Piece::EMPTY,
+        }
     }
 }
 
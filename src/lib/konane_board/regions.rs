@@ -0,0 +1,201 @@
+use super::{Board, Piece, Square};
+
+impl Board {
+    /// Split the board into regions that can never affect each other, whatever moves are
+    /// played, so that the position is the disjunctive sum of the regions: on each turn the
+    /// player to move picks one region and moves in it. Each region is returned as a board of
+    /// the same size holding just that region's pieces, ordered by their first piece.
+    ///
+    /// Every capture takes a piece from the region it happens in, so a piece can travel no
+    /// further than two points for each enemy piece in its region. It also stays on points
+    /// whose row and column have the same parity as where it started. Two pieces are put in
+    /// the same region if, given how far each could travel, they could ever end up next to
+    /// each other or on the same point, and regions are merged until none of them can reach
+    /// another.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, Piece};
+    ///
+    /// let mut board = Board::create_empty();
+    /// let _ = board.set_piece((0, 0), Piece::BLACK);
+    /// let _ = board.set_piece((0, 1), Piece::WHITE);
+    /// let _ = board.set_piece((5, 4), Piece::WHITE);
+    /// let _ = board.set_piece((5, 5), Piece::BLACK);
+    ///
+    /// let regions = board.regions();
+    /// assert_eq!(regions.len(), 2);
+    /// assert_eq!(regions[0].get_piece((0, 0)), Ok(Piece::BLACK));
+    /// assert_eq!(regions[0].count(Piece::WHITE), 1);
+    /// assert_eq!(regions[1].get_piece((5, 5)), Ok(Piece::BLACK));
+    ///
+    /// assert_eq!(Board::default().regions().len(), 1);
+    /// ```
+    pub fn regions(&self) -> Vec<Board> {
+        let pieces = self
+            .squares()
+            .map(|square| {
+                let piece = self.get_piece(square).expect("the point is on the board");
+                (square, piece)
+            })
+            .filter(|&(_, piece)| piece != Piece::EMPTY)
+            .collect::<Vec<_>>();
+
+        // Every piece starts in a region of its own, named by the index of one of its pieces
+        let mut region = (0..pieces.len()).collect::<Vec<_>>();
+
+        loop {
+            // How far each piece could travel, with what's in its region now
+            let reach = (0..pieces.len())
+                .map(|index| {
+                    let enemy = pieces[index].1.opponent();
+                    let enemies = (0..pieces.len())
+                        .filter(|&other| region[other] == region[index] && pieces[other].1 == enemy)
+                        .count();
+
+                    2 * enemies
+                })
+                .collect::<Vec<_>>();
+
+            let mut merged = false;
+            for first in 0..pieces.len() {
+                for second in first + 1..pieces.len() {
+                    let (from, to) = (region[first], region[second]);
+                    if from == to
+                        || !could_meet(
+                            pieces[first].0,
+                            pieces[second].0,
+                            reach[first] + reach[second],
+                        )
+                    {
+                        continue;
+                    }
+
+                    for name in region.iter_mut().filter(|name| **name == to) {
+                        *name = from;
+                    }
+                    merged = true;
+                }
+            }
+
+            if !merged {
+                break;
+            }
+        }
+
+        let mut names = vec![];
+        for &name in &region {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        names
+            .into_iter()
+            .map(|name| {
+                let mut board = Board::with_size(self.width(), self.height())
+                    .expect("the board already has this size");
+                for (index, &(square, piece)) in pieces.iter().enumerate() {
+                    if region[index] == name {
+                        let _ = board.set_piece(square, piece);
+                    }
+                }
+
+                board
+            })
+            .collect()
+    }
+}
+
+/// Whether pieces starting at `first` and `second` could ever be next to each other or on the
+/// same point, if between them they can travel `reach` points.
+fn could_meet(first: Square, second: Square, reach: usize) -> bool {
+    let row_parity = first.row() % 2 != second.row() % 2;
+    let col_parity = first.col() % 2 != second.col() % 2;
+    if row_parity && col_parity {
+        return false;
+    }
+
+    first.distance(second) <= reach + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{testing::xorshift, Board, Piece};
+
+    /// Scatter pieces thinly over a board, so that it tends to fall apart into regions.
+    fn sparse_board(seed: &mut u64) -> Board {
+        let mut board = Board::with_size(10, 10).unwrap();
+
+        for row in 0..10 {
+            for col in 0..10 {
+                let piece = match xorshift(seed) % 16 {
+                    0 => Piece::BLACK,
+                    1 => Piece::WHITE,
+                    _ => Piece::EMPTY,
+                };
+                let _ = board.set_piece((row, col), piece);
+            }
+        }
+
+        board
+    }
+
+    #[test]
+    fn diagonal_neighbours_never_meet() {
+        let mut board = Board::create_empty();
+        let _ = board.set_piece((2, 2), Piece::BLACK);
+        let _ = board.set_piece((3, 3), Piece::WHITE);
+
+        assert_eq!(board.regions().len(), 2);
+
+        let _ = board.set_piece((2, 3), Piece::WHITE);
+        assert_eq!(board.regions().len(), 1);
+    }
+
+    #[test]
+    fn regions_play_independently() {
+        let mut seed = 0x9e37_79b9_7f4a_7c15;
+        let mut split = 0;
+
+        for _ in 0..40 {
+            let mut board = sparse_board(&mut seed);
+            let mut regions = board.regions();
+            split += (regions.len() > 1) as usize;
+
+            let pieces =
+                |boards: &[Board], piece| boards.iter().map(|b| b.count(piece)).sum::<usize>();
+            assert_eq!(pieces(&regions, Piece::BLACK), board.count(Piece::BLACK));
+            assert_eq!(pieces(&regions, Piece::WHITE), board.count(Piece::WHITE));
+
+            // Play the game out, checking every move is a move in exactly one region and
+            // plays out the same way there
+            let mut side = Piece::BLACK;
+            loop {
+                let mut in_regions = regions
+                    .iter()
+                    .flat_map(|region| region.legal_moves(side))
+                    .collect::<Vec<_>>();
+                in_regions.sort();
+                assert_eq!(board.legal_moves(side), in_regions);
+
+                if in_regions.is_empty() {
+                    break;
+                }
+                let jump = in_regions[xorshift(&mut seed) as usize % in_regions.len()];
+
+                let captured = board.apply_move(jump).unwrap();
+                let region = regions
+                    .iter_mut()
+                    .find(|region| region.get_piece(jump.from()) == Ok(side))
+                    .unwrap();
+                assert_eq!(region.apply_move(jump).unwrap(), captured);
+
+                side = side.opponent();
+            }
+        }
+
+        assert!(split >= 10);
+    }
+}
@@ -0,0 +1,159 @@
+//! Serde support for boards and moves, enabled with the `serde` feature.
+//!
+//! Squares are written algebraically, such as `"c3"`, and moves as every square the piece stops
+//! on, such as `"c3-c5-c7"`. Pieces are `"black"`, `"white"` or `"empty"` and directions are
+//! `"up"`, `"down"`, `"left"` or `"right"`. A board is an object holding its `width`, its
+//! `height` and its `rows` from the top down, each a string with `B` for black, `W` for white
+//! and `.` for an empty point:
+//!
+//! ```json
+//! { "width": 4, "height": 2, "rows": ["BW..", "...W"] }
+//! ```
+//!
+//! The moves applied to a board aren't kept, so a board read back can't undo them.
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Board, BoardBuilder, Move, Piece, Setup, Square};
+
+/// The shape a board is written in.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BoardShape {
+    width: usize,
+    height: usize,
+    rows: Vec<String>,
+}
+
+impl Serialize for Square {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Square {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Square, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for Move {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Move {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Move, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(D::Error::custom)
+    }
+}
+
+impl Serialize for Board {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let rows = (0..self.height())
+            .map(|row| {
+                (0..self.width())
+                    .map(|col| match self.get_piece((row, col)) {
+                        Ok(Piece::BLACK) => 'B',
+                        Ok(Piece::WHITE) => 'W',
+                        _ => '.',
+                    })
+                    .collect()
+            })
+            .collect();
+
+        BoardShape {
+            width: self.width(),
+            height: self.height(),
+            rows,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Board {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Board, D::Error> {
+        let shape = BoardShape::deserialize(deserializer)?;
+
+        BoardBuilder::new()
+            .size(shape.width, shape.height)
+            .setup(Setup::Pattern(shape.rows.join("\n")))
+            .build()
+            .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{Board, Direction, Move, Piece, Square};
+
+    #[test]
+    fn board_schema() {
+        let mut board = Board::with_size(4, 2).unwrap();
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((0, 1), Piece::WHITE);
+        let _ = board.set_piece((1, 3), Piece::WHITE);
+
+        let value = serde_json::to_value(&board).unwrap();
+        assert_eq!(
+            value,
+            json!({ "width": 4, "height": 2, "rows": ["BW..", "...W"] })
+        );
+        assert_eq!(serde_json::from_value::<Board>(value).unwrap(), board);
+
+        let standard = serde_json::to_string(&Board::default()).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Board>(&standard).unwrap(),
+            Board::default()
+        );
+    }
+
+    #[test]
+    fn moves_and_pieces_are_strings() {
+        let jump = Move::new((2, 2), Direction::Down, 2);
+
+        assert_eq!(serde_json::to_value(jump).unwrap(), json!("c3-c5-c7"));
+        assert_eq!(
+            serde_json::from_value::<Move>(json!("c3-c5-c7")).unwrap(),
+            jump
+        );
+        assert_eq!(
+            serde_json::to_value(Square::new(0, 1)).unwrap(),
+            json!("b1")
+        );
+        assert_eq!(serde_json::to_value(Piece::BLACK).unwrap(), json!("black"));
+        assert_eq!(
+            serde_json::to_value(Direction::Left).unwrap(),
+            json!("left")
+        );
+        assert_eq!(
+            serde_json::from_value::<Piece>(json!("empty")).unwrap(),
+            Piece::EMPTY
+        );
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        let bad = [
+            json!({ "width": 4, "height": 2, "rows": ["BW..", "..W"] }),
+            json!({ "width": 4, "height": 2, "rows": ["BW.."] }),
+            json!({ "width": 0, "height": 2, "rows": [] }),
+            json!({ "width": 2, "height": 1, "rows": ["BX"] }),
+            json!({ "width": 2, "height": 1, "rows": ["BW"], "side": "black" }),
+        ];
+        for value in bad {
+            assert!(serde_json::from_value::<Board>(value).is_err());
+        }
+
+        assert!(serde_json::from_value::<Move>(json!("c3-c4")).is_err());
+        assert!(serde_json::from_value::<Square>(json!("3c")).is_err());
+        assert!(serde_json::from_value::<Piece>(json!("BLACK")).is_err());
+    }
+}
@@ -0,0 +1,229 @@
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use super::Direction;
+use crate::KonaneError;
+
+/// A point on the board, named by its row and column so the two can't be mixed up.
+///
+/// Rows grow downwards and columns grow to the right, both counting from zero in the top left
+/// corner. Squares are also written algebraically, with a letter for the column and a number
+/// for the row counting from one, so that the top left corner is `a1` and the square below it
+/// is `a2`. Letters run from `a` to `t`, which covers the largest boards.
+///
+/// Squares convert to and from `(row, col)` tuples, so anything that takes a square can also
+/// be given a tuple.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{Direction, Square};
+///
+/// let square = "c2".parse::<Square>().unwrap();
+/// assert_eq!(square, Square::new(1, 2));
+/// assert_eq!(square.to_string(), "c2");
+///
+/// assert_eq!(square.neighbour(Direction::Down), Some(Square::new(2, 2)));
+/// assert_eq!(square.step(Direction::Up, 2), None);
+/// assert_eq!(Square::new(1, 2).index(6), 8);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Square {
+    row: usize,
+    col: usize,
+}
+
+impl Square {
+    /// The square at `row` and `col`.
+    pub fn new(row: usize, col: usize) -> Square {
+        Square { row, col }
+    }
+
+    /// The square at the given index on a board `width` points wide, counting row by row from
+    /// the top left.
+    pub fn from_index(index: usize, width: usize) -> Square {
+        Square::new(index / width, index % width)
+    }
+
+    /// The square's row, counting down from zero at the top.
+    pub fn row(self) -> usize {
+        self.row
+    }
+
+    /// The square's column, counting right from zero at the left.
+    pub fn col(self) -> usize {
+        self.col
+    }
+
+    /// The square's index on a board `width` points wide, counting row by row from the top
+    /// left.
+    pub fn index(self, width: usize) -> usize {
+        self.row * width + self.col
+    }
+
+    /// The square `distance` points away in `direction`, or `None` if that would be past the
+    /// top or left edge. The bottom and right edges depend on the board, so are left for it to
+    /// check.
+    pub fn step(self, direction: Direction, distance: usize) -> Option<Square> {
+        let (row_offset, col_offset) = direction.offset();
+        let distance = distance as isize;
+
+        Some(Square::new(
+            self.row.checked_add_signed(row_offset * distance)?,
+            self.col.checked_add_signed(col_offset * distance)?,
+        ))
+    }
+
+    /// The square next to this one in `direction`, or `None` past the top or left edge.
+    pub fn neighbour(self, direction: Direction) -> Option<Square> {
+        self.step(direction, 1)
+    }
+
+    /// Every square next to this one, in the order of [`Direction::ALL`], leaving out any past
+    /// the top or left edge.
+    pub fn neighbours(self) -> Vec<Square> {
+        Direction::ALL
+            .iter()
+            .filter_map(|&direction| self.neighbour(direction))
+            .collect()
+    }
+
+    /// The direction to go in to get from this square to `other`, if they're in the same row
+    /// or column and aren't the same square.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Direction, Square};
+    ///
+    /// let from = Square::new(3, 3);
+    ///
+    /// assert_eq!(from.direction_to(Square::new(0, 3)), Some(Direction::Up));
+    /// assert_eq!(from.direction_to(Square::new(3, 5)), Some(Direction::Right));
+    /// assert_eq!(from.direction_to(Square::new(4, 4)), None);
+    /// assert_eq!(from.direction_to(from), None);
+    /// ```
+    pub fn direction_to(self, other: Square) -> Option<Direction> {
+        match (self.row.cmp(&other.row), self.col.cmp(&other.col)) {
+            (Ordering::Greater, Ordering::Equal) => Some(Direction::Up),
+            (Ordering::Less, Ordering::Equal) => Some(Direction::Down),
+            (Ordering::Equal, Ordering::Greater) => Some(Direction::Left),
+            (Ordering::Equal, Ordering::Less) => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    /// The number of points between this square and `other` along rows and columns.
+    pub fn distance(self, other: Square) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl From<(usize, usize)> for Square {
+    fn from((row, col): (usize, usize)) -> Square {
+        Square::new(row, col)
+    }
+}
+
+impl From<Square> for (usize, usize) {
+    fn from(square: Square) -> (usize, usize) {
+        (square.row, square.col)
+    }
+}
+
+/// Writes the square algebraically, such as `c2`.
+impl Display for Square {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match u8::try_from(self.col).ok().filter(|col| *col < 26) {
+            Some(col) => write!(f, "{}{}", (b'a' + col) as char, self.row + 1),
+            None => write!(f, "({}, {})", self.row, self.col),
+        }
+    }
+}
+
+/// Reads a square written algebraically, such as `c2`.
+impl FromStr for Square {
+    type Err = KonaneError;
+
+    fn from_str(name: &str) -> Result<Square, KonaneError> {
+        let invalid = || KonaneError::InvalidNotation(format!("'{}' isn't a square", name));
+
+        let mut chars = name.chars();
+        let col = match chars.next() {
+            Some(letter @ 'a'..='z') => letter as usize - 'a' as usize,
+            _ => return Err(invalid()),
+        };
+
+        let number = chars.as_str();
+        if !number.starts_with(|digit: char| ('1'..='9').contains(&digit)) {
+            return Err(invalid());
+        }
+        let row = number.parse::<usize>().map_err(|_| invalid())? - 1;
+
+        Ok(Square::new(row, col))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Direction, KonaneError, Square};
+
+    #[test]
+    fn algebraic_names() {
+        assert_eq!(Square::new(0, 0).to_string(), "a1");
+        assert_eq!(Square::new(11, 19).to_string(), "t12");
+
+        assert_eq!("c3".parse(), Ok(Square::new(2, 2)));
+        assert_eq!("t12".parse(), Ok(Square::new(11, 19)));
+
+        for name in ["a0", "a01", "A1", "c", "", "c3x", "3c"] {
+            assert_eq!(
+                name.parse::<Square>(),
+                Err(KonaneError::InvalidNotation(format!(
+                    "'{}' isn't a square",
+                    name
+                )))
+            );
+        }
+
+        for row in 0..20 {
+            for col in 0..20 {
+                let square = Square::new(row, col);
+                assert_eq!(square.to_string().parse(), Ok(square));
+            }
+        }
+    }
+
+    #[test]
+    fn indices_and_neighbours() {
+        for width in 1..=20 {
+            for index in 0..width * 20 {
+                assert_eq!(Square::from_index(index, width).index(width), index);
+            }
+        }
+
+        assert_eq!(
+            Square::new(0, 0).neighbours(),
+            vec![Square::new(1, 0), Square::new(0, 1)]
+        );
+        assert_eq!(Square::new(2, 2).neighbours().len(), 4);
+
+        for direction in Direction::ALL {
+            let square = Square::new(5, 5).step(direction, 3).unwrap();
+            assert_eq!(Square::new(5, 5).direction_to(square), Some(direction));
+            assert_eq!(Square::new(5, 5).distance(square), 3);
+        }
+    }
+
+    #[test]
+    fn orders_row_by_row() {
+        let mut squares = vec![Square::new(1, 0), Square::new(0, 5), Square::new(0, 1)];
+        squares.sort();
+
+        assert_eq!(
+            squares,
+            vec![Square::new(0, 1), Square::new(0, 5), Square::new(1, 0)]
+        );
+        assert_eq!(Square::from((3, 4)), Square::new(3, 4));
+        assert_eq!(<(usize, usize)>::from(Square::new(3, 4)), (3, 4));
+    }
+}
@@ -0,0 +1,60 @@
+use super::{Board, Piece};
+use crate::splitmix::splitmix64;
+
+/// The number of points on the largest board.
+const POINTS: usize = Board::MAX_SIZE * Board::MAX_SIZE;
+
+/// A random key for every colour of piece on every point, indexed by colour then point.
+static PIECE_KEYS: [[u64; POINTS]; 2] = piece_keys();
+
+/// Folded into a key when White is to move.
+pub(crate) const WHITE_TO_MOVE_KEY: u64 = splitmix64(0x7768_6974_6521).1;
+
+/// The key of a piece on the point with the given index, or zero for an empty point.
+pub(crate) fn piece_key(piece: Piece, index: usize) -> u64 {
+    match piece {
+        Piece::BLACK => PIECE_KEYS[0][index],
+        Piece::WHITE => PIECE_KEYS[1][index],
+        Piece::EMPTY => 0,
+    }
+}
+
+/// The key of an empty board, so that boards of different sizes get different keys.
+pub(crate) fn size_key(width: usize, height: usize) -> u64 {
+    splitmix64(((width as u64) << 32 | height as u64) ^ 0x7369_7a65_0000_0000).1
+}
+
+const fn piece_keys() -> [[u64; POINTS]; 2] {
+    let mut keys = [[0; POINTS]; 2];
+    let mut state = 0x6b6f_6e61_6e65_0000;
+
+    let mut colour = 0;
+    while colour < 2 {
+        let mut index = 0;
+        while index < POINTS {
+            let (next, key) = splitmix64(state);
+            keys[colour][index] = key;
+            state = next;
+            index += 1;
+        }
+        colour += 1;
+    }
+
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{piece_key, PIECE_KEYS};
+    use crate::Piece;
+
+    #[test]
+    fn keys_are_distinct() {
+        let mut keys = PIECE_KEYS.concat();
+        keys.sort();
+        keys.dedup();
+
+        assert_eq!(keys.len(), PIECE_KEYS.len() * PIECE_KEYS[0].len());
+        assert_eq!(piece_key(Piece::EMPTY, 0), 0);
+    }
+}
@@ -0,0 +1,519 @@
+use crate::{Board, KonaneError, Move, Piece, Square};
+
+/// The two stages of a game of Kōnane.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Phase {
+    /// Black removes one of its pieces from the centre or a corner, then White removes one of
+    /// its pieces next to the gap. No jumps can be made until both have done so.
+    Opening,
+    /// Players take turns jumping over each other's pieces.
+    Jumping,
+}
+
+/// A single turn taken by one of the players.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Turn {
+    /// A piece was taken off the board during the opening.
+    Removal(Square),
+    /// A piece jumped over one or more enemy pieces.
+    Jump(Move),
+}
+
+/// A game of Kōnane in progress: the board, whose turn it is, and every turn taken so far.
+/// The game is over once the player to move has nothing legal to do, at which point their
+/// opponent has won.
+#[derive(Clone, Debug)]
+pub struct Game {
+    board: Board,
+    side_to_move: Piece,
+    phase: Phase,
+    history: Vec<Turn>,
+}
+
+/// Starts from the standard opening, with Black to remove a piece.
+impl Default for Game {
+    fn default() -> Self {
+        Game::opening(Board::default())
+    }
+}
+
+impl Game {
+    /// Start a game from the opening phase, with Black to remove the first piece.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, Game, Phase, Piece};
+    ///
+    /// let game = Game::opening(Board::default());
+    ///
+    /// assert_eq!(game.phase(), Phase::Opening);
+    /// assert_eq!(game.side_to_move(), Piece::BLACK);
+    /// assert!(game.legal_moves().is_empty());
+    /// ```
+    pub fn opening(board: Board) -> Game {
+        Game {
+            board,
+            side_to_move: Piece::BLACK,
+            phase: Phase::Opening,
+            history: vec![],
+        }
+    }
+
+    /// Start a game from a position where jumping has already begun, with `first` to move,
+    /// which must be Black or White.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, Game, KonaneError, Piece};
+    ///
+    /// let game = Game::new(Board::create_empty(), Piece::WHITE).unwrap();
+    ///
+    /// assert_eq!(game.side_to_move(), Piece::WHITE);
+    /// assert_eq!(game.ply(), 0);
+    ///
+    /// assert_eq!(
+    ///     Game::new(Board::create_empty(), Piece::EMPTY).unwrap_err(),
+    ///     KonaneError::EmptySide
+    /// );
+    /// ```
+    pub fn new(board: Board, first: Piece) -> Result<Game, KonaneError> {
+        if first == Piece::EMPTY {
+            return Err(KonaneError::EmptySide);
+        }
+
+        Ok(Game {
+            board,
+            side_to_move: first,
+            phase: Phase::Jumping,
+            history: vec![],
+        })
+    }
+
+    /// The current position.
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// The player whose turn it is.
+    pub fn side_to_move(&self) -> Piece {
+        self.side_to_move
+    }
+
+    /// Whether the game is still in its opening removals.
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// A 64 bit Zobrist key identifying the position and the player to move.
+    pub fn hash_key(&self) -> u64 {
+        self.board.hash_key(self.side_to_move)
+    }
+
+    /// Every turn taken so far, oldest first.
+    pub fn history(&self) -> &[Turn] {
+        &self.history
+    }
+
+    /// The number of turns taken so far, including opening removals.
+    pub fn ply(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Every legal move for the player to move. There are none during the opening.
+    pub fn legal_moves(&self) -> Vec<Move> {
+        match self.phase {
+            Phase::Opening => vec![],
+            Phase::Jumping => self.board.legal_moves(self.side_to_move),
+        }
+    }
+
+    /// Every point the player to move may remove a piece from. Black may remove one of its
+    /// pieces from the centre of the board or a corner, and White may then remove one of its
+    /// pieces orthogonally adjacent to the point Black emptied. There are none once jumping
+    /// has begun.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, Game, Piece, Square};
+    ///
+    /// let mut board = Board::create_empty();
+    /// let _ = board.set_piece((0, 0), Piece::BLACK);
+    /// let _ = board.set_piece((0, 1), Piece::WHITE);
+    /// let _ = board.set_piece((1, 1), Piece::BLACK);
+    /// let _ = board.set_piece((2, 2), Piece::BLACK);
+    ///
+    /// let mut game = Game::opening(board);
+    /// assert_eq!(
+    ///     game.legal_removals(),
+    ///     vec![Square::new(0, 0), Square::new(2, 2)]
+    /// );
+    ///
+    /// game.remove((0, 0)).unwrap();
+    /// assert_eq!(game.legal_removals(), vec![Square::new(0, 1)]);
+    /// ```
+    pub fn legal_removals(&self) -> Vec<Square> {
+        if self.phase == Phase::Jumping {
+            return vec![];
+        }
+
+        let candidates = match self.history.last() {
+            Some(Turn::Removal(gap)) => gap.neighbours(),
+            _ => opening_points(&self.board),
+        };
+
+        candidates
+            .into_iter()
+            .filter(|&square| self.board.get_piece(square) == Ok(self.side_to_move))
+            .collect()
+    }
+
+    /// Remove one of the player to move's pieces during the opening, passing the turn to their
+    /// opponent. Jumping begins, with Black to move, once both players have removed a piece.
+    /// Returns the removed piece.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, Game, KonaneError, Phase, Piece, Square};
+    ///
+    /// let mut game = Game::opening(Board::default());
+    ///
+    /// // Black may only remove from the centre or a corner
+    /// assert_eq!(
+    ///     game.remove((0, 2)),
+    ///     Err(KonaneError::IllegalRemoval(Square::new(0, 2)))
+    /// );
+    ///
+    /// assert_eq!(game.remove((0, 0)), Ok(Piece::BLACK));
+    /// assert_eq!(game.side_to_move(), Piece::WHITE);
+    /// assert_eq!(game.phase(), Phase::Opening);
+    ///
+    /// assert_eq!(game.remove((0, 1)), Ok(Piece::WHITE));
+    /// assert_eq!(game.side_to_move(), Piece::BLACK);
+    /// assert_eq!(game.phase(), Phase::Jumping);
+    /// ```
+    pub fn remove(&mut self, square: impl Into<Square>) -> Result<Piece, KonaneError> {
+        let square = square.into();
+        if self.phase != Phase::Opening {
+            return Err(KonaneError::WrongPhase(self.phase));
+        }
+        if let Some(winner) = self.result() {
+            return Err(KonaneError::GameOver { winner });
+        }
+
+        self.board.get_piece(square)?;
+        if !self.legal_removals().contains(&square) {
+            return Err(KonaneError::IllegalRemoval(square));
+        }
+
+        let removed = self.board.set_piece(square, Piece::EMPTY)?;
+
+        self.history.push(Turn::Removal(square));
+        if self.side_to_move == Piece::WHITE {
+            self.phase = Phase::Jumping;
+        }
+        self.side_to_move = self.side_to_move.opponent();
+
+        Ok(removed)
+    }
+
+    /// Play a move for the player to move, passing the turn to their opponent. Returns the
+    /// captured pieces. The move is rejected if it isn't legal for the player to move, the game
+    /// is still in its opening, or the game is over.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, Direction, Game, KonaneError, Move, Piece};
+    ///
+    /// let mut board = Board::create_empty();
+    /// let _ = board.set_piece((0, 0), Piece::BLACK);
+    /// let _ = board.set_piece((0, 1), Piece::WHITE);
+    /// let _ = board.set_piece((1, 1), Piece::BLACK);
+    ///
+    /// let mut game = Game::new(board, Piece::BLACK).unwrap();
+    ///
+    /// // White's piece can't move on Black's turn
+    /// assert_eq!(
+    ///     game.play(Move::new((0, 1), Direction::Down, 1)),
+    ///     Err(KonaneError::WrongSide {
+    ///         expected: Piece::BLACK,
+    ///         found: Piece::WHITE
+    ///     })
+    /// );
+    ///
+    /// let captured = game.play(Move::new((0, 0), Direction::Right, 1));
+    /// assert_eq!(captured, Ok(vec![Piece::WHITE]));
+    /// assert_eq!(game.side_to_move(), Piece::WHITE);
+    /// assert_eq!(game.ply(), 1);
+    /// ```
+    pub fn play(&mut self, jump: Move) -> Result<Vec<Piece>, KonaneError> {
+        if self.phase != Phase::Jumping {
+            return Err(KonaneError::WrongPhase(self.phase));
+        }
+        if let Some(winner) = self.result() {
+            return Err(KonaneError::GameOver { winner });
+        }
+
+        let from = jump.from();
+        match self.board.get_piece(from)? {
+            Piece::EMPTY => return Err(KonaneError::EmptyPoint(from)),
+            piece if piece != self.side_to_move => {
+                return Err(KonaneError::WrongSide {
+                    expected: self.side_to_move,
+                    found: piece,
+                })
+            }
+            _ => {}
+        }
+
+        let captured = self.board.apply_move(jump)?;
+
+        self.history.push(Turn::Jump(jump));
+        self.side_to_move = self.side_to_move.opponent();
+
+        Ok(captured)
+    }
+
+    /// Take back the last turn, handing the turn back to the player who took it. Returns the
+    /// turn taken back, or [`KonaneError::NothingToUndo`] at the start of the game.
+    pub fn undo(&mut self) -> Result<Turn, KonaneError> {
+        let turn = self.history.pop().ok_or(KonaneError::NothingToUndo)?;
+        let mover = self.side_to_move.opponent();
+
+        match turn {
+            Turn::Removal(square) => {
+                self.board.set_piece(square, mover)?;
+                self.phase = Phase::Opening;
+            }
+            Turn::Jump(_) => {
+                self.board.unmake_move()?;
+            }
+        }
+        self.side_to_move = mover;
+
+        Ok(turn)
+    }
+
+    /// The winner of the game, or `None` while the player to move still has something legal to
+    /// do.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Board, Direction, Game, Move, Piece};
+    ///
+    /// let mut board = Board::create_empty();
+    /// let _ = board.set_piece((0, 0), Piece::BLACK);
+    /// let _ = board.set_piece((0, 1), Piece::WHITE);
+    ///
+    /// let mut game = Game::new(board, Piece::BLACK).unwrap();
+    /// assert_eq!(game.result(), None);
+    ///
+    /// game.play(Move::new((0, 0), Direction::Right, 1)).unwrap();
+    /// assert_eq!(game.result(), Some(Piece::BLACK));
+    /// ```
+    pub fn result(&self) -> Option<Piece> {
+        let stuck = match self.phase {
+            Phase::Opening => self.legal_removals().is_empty(),
+            Phase::Jumping => self.legal_moves().is_empty(),
+        };
+
+        if stuck {
+            Some(self.side_to_move.opponent())
+        } else {
+            None
+        }
+    }
+}
+
+/// The points Black may open the game from: the corners and the centre of the board. The
+/// centre is the middle four points on an even sized board, shrinking to the middle two or
+/// one along odd sides.
+fn opening_points(board: &Board) -> Vec<Square> {
+    let (last_row, last_col) = (board.height() - 1, board.width() - 1);
+
+    let mut points = vec![
+        Square::new(0, 0),
+        Square::new(0, last_col),
+        Square::new(last_row, 0),
+        Square::new(last_row, last_col),
+    ];
+    for row in [last_row / 2, board.height() / 2] {
+        for col in [last_col / 2, board.width() / 2] {
+            points.push(Square::new(row, col));
+        }
+    }
+
+    points.sort();
+    points.dedup();
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Board, BoardBuilder, Direction, Game, KonaneError, Move, Phase, Piece, Square, Turn,
+    };
+
+    fn corridor() -> Game {
+        let mut board = Board::create_empty();
+
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((0, 1), Piece::WHITE);
+        let _ = board.set_piece((0, 3), Piece::WHITE);
+        let _ = board.set_piece((1, 2), Piece::BLACK);
+
+        Game::new(board, Piece::BLACK).unwrap()
+    }
+
+    #[test]
+    fn only_black_or_white_can_move_first() {
+        let board = corridor().board().clone();
+
+        assert_eq!(
+            Game::new(board.clone(), Piece::EMPTY).unwrap_err(),
+            KonaneError::EmptySide
+        );
+        assert_eq!(
+            Game::new(board, Piece::WHITE).unwrap().side_to_move(),
+            Piece::WHITE
+        );
+    }
+
+    #[test]
+    fn turns_alternate() {
+        let mut game = corridor();
+
+        assert!(game.play(Move::new((0, 0), Direction::Right, 1)).is_ok());
+        assert_eq!(game.side_to_move(), Piece::WHITE);
+
+        // Black can't move twice in a row
+        assert!(game.play(Move::new((0, 2), Direction::Right, 1)).is_err());
+
+        assert!(game.play(Move::new((0, 3), Direction::Left, 1)).is_ok());
+        assert_eq!(game.side_to_move(), Piece::BLACK);
+        assert_eq!(
+            game.history(),
+            &[
+                Turn::Jump(Move::new((0, 0), Direction::Right, 1)),
+                Turn::Jump(Move::new((0, 3), Direction::Left, 1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn game_over_when_side_to_move_is_stuck() {
+        let mut game = corridor();
+
+        game.play(Move::new((0, 0), Direction::Right, 2)).unwrap();
+
+        assert!(game.legal_moves().is_empty());
+        assert_eq!(game.result(), Some(Piece::BLACK));
+        assert_eq!(
+            game.play(Move::new((0, 4), Direction::Left, 1)),
+            Err(KonaneError::GameOver {
+                winner: Piece::BLACK
+            })
+        );
+    }
+
+    #[test]
+    fn undo_hands_back_the_turn() {
+        let mut game = corridor();
+        let start = game.board().to_string();
+
+        let jump = Move::new((0, 0), Direction::Right, 2);
+        game.play(jump).unwrap();
+
+        assert_eq!(game.undo(), Ok(Turn::Jump(jump)));
+        assert_eq!(game.side_to_move(), Piece::BLACK);
+        assert_eq!(game.ply(), 0);
+        assert_eq!(game.board().to_string(), start);
+        assert_eq!(game.undo(), Err(KonaneError::NothingToUndo));
+    }
+
+    #[test]
+    fn opening_removals_lead_into_jumping() {
+        let mut game = Game::default();
+
+        assert_eq!(game.phase(), Phase::Opening);
+        assert_eq!(
+            game.play(Move::new((0, 0), Direction::Right, 1)),
+            Err(KonaneError::WrongPhase(Phase::Opening))
+        );
+
+        for &square in &game.legal_removals() {
+            assert_eq!(game.board().get_piece(square), Ok(Piece::BLACK));
+        }
+
+        game.remove((2, 2)).unwrap();
+
+        // White must remove next to the gap Black left
+        assert_eq!(
+            game.remove((0, 1)),
+            Err(KonaneError::IllegalRemoval(Square::new(0, 1)))
+        );
+        for &square in &game.legal_removals() {
+            assert_eq!(square.distance(Square::new(2, 2)), 1);
+        }
+
+        let &square = game.legal_removals().first().unwrap();
+        game.remove(square).unwrap();
+
+        assert_eq!(game.phase(), Phase::Jumping);
+        assert_eq!(game.side_to_move(), Piece::BLACK);
+        assert!(game.legal_removals().is_empty());
+        assert_eq!(
+            game.remove((0, 0)),
+            Err(KonaneError::WrongPhase(Phase::Jumping))
+        );
+    }
+
+    #[test]
+    fn undo_removal_restores_opening() {
+        let mut game = Game::default();
+        let start = game.board().to_string();
+
+        game.remove((0, 0)).unwrap();
+        game.remove((0, 1)).unwrap();
+
+        assert_eq!(game.undo(), Ok(Turn::Removal(Square::new(0, 1))));
+        assert_eq!(game.phase(), Phase::Opening);
+        assert_eq!(game.side_to_move(), Piece::WHITE);
+
+        assert_eq!(game.undo(), Ok(Turn::Removal(Square::new(0, 0))));
+        assert_eq!(game.side_to_move(), Piece::BLACK);
+        assert_eq!(game.board().to_string(), start);
+    }
+
+    #[test]
+    fn opening_points_on_odd_boards() {
+        let board = BoardBuilder::new().size(5, 5).build().unwrap();
+        let game = Game::opening(board);
+
+        assert_eq!(
+            game.legal_removals(),
+            vec![
+                Square::new(0, 0),
+                Square::new(0, 4),
+                Square::new(2, 2),
+                Square::new(4, 0),
+                Square::new(4, 4)
+            ]
+        );
+    }
+}
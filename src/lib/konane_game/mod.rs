@@ -0,0 +1,6 @@
+mod game;
+mod notation;
+#[cfg(feature = "serde")]
+mod serialize;
+
+pub use game::{Game, Phase, Turn};
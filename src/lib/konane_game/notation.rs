@@ -0,0 +1,193 @@
+use std::str::FromStr;
+
+use super::{Game, Phase, Turn};
+use crate::{Board, KonaneError, Piece, Square};
+
+impl Game {
+    /// Write the position as a single line of text, which [`Game::from_str`] reads back into a
+    /// game in the same position. The turns that led there aren't kept.
+    ///
+    /// The notation is the [board's notation](Board::to_notation), then `b` or `w` for the
+    /// player to move, then `o` during the opening or `j` once jumping has begun. When White
+    /// is to remove a piece, the [square](crate::Square) Black emptied follows, such as `c4`,
+    /// since White must remove a piece next to it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{BoardBuilder, Game};
+    ///
+    /// let mut game = Game::opening(BoardBuilder::new().size(4, 4).build().unwrap());
+    /// assert_eq!(game.to_notation(), "4x4 BWBW/WBWB/BWBW/WBWB b o");
+    ///
+    /// game.remove((0, 0)).unwrap();
+    /// assert_eq!(game.to_notation(), "4x4 1WBW/WBWB/BWBW/WBWB w o a1");
+    ///
+    /// game.remove((0, 1)).unwrap();
+    /// assert_eq!(game.to_notation(), "4x4 2BW/WBWB/BWBW/WBWB b j");
+    /// ```
+    pub fn to_notation(&self) -> String {
+        let side = match self.side_to_move() {
+            Piece::WHITE => 'w',
+            _ => 'b',
+        };
+
+        match (self.phase(), self.side_to_move()) {
+            (Phase::Jumping, _) => format!("{} {} j", self.board().to_notation(), side),
+            (Phase::Opening, Piece::WHITE) => {
+                let Some(Turn::Removal(gap)) = self.history().last() else {
+                    unreachable!("White only removes a piece after Black has");
+                };
+
+                format!("{} w o {}", self.board().to_notation(), gap)
+            }
+            (Phase::Opening, _) => format!("{} {} o", self.board().to_notation(), side),
+        }
+    }
+}
+
+/// Reads a position written by [`Game::to_notation`]. The game starts with no turns in its
+/// history, apart from Black's removal when White is to remove a piece.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{Game, KonaneError, Phase, Piece};
+///
+/// let game = "4x1 BW1B w j".parse::<Game>().unwrap();
+/// assert_eq!(game.side_to_move(), Piece::WHITE);
+/// assert_eq!(game.phase(), Phase::Jumping);
+/// assert_eq!(game.result(), Some(Piece::BLACK));
+///
+/// assert_eq!(
+///     "4x1 BW1B w".parse::<Game>().unwrap_err(),
+///     KonaneError::InvalidNotation(
+///         "expected a board, side and phase separated by spaces".to_string()
+///     )
+/// );
+/// ```
+impl FromStr for Game {
+    type Err = KonaneError;
+
+    fn from_str(notation: &str) -> Result<Game, KonaneError> {
+        let invalid = |reason: String| KonaneError::InvalidNotation(reason);
+
+        let fields = notation.split_whitespace().collect::<Vec<_>>();
+        let (board, side, phase, gap) = match fields[..] {
+            [size, rows, side, phase] => ([size, rows].join(" "), side, phase, None),
+            [size, rows, side, phase, gap] => ([size, rows].join(" "), side, phase, Some(gap)),
+            _ => {
+                return Err(invalid(
+                    "expected a board, side and phase separated by spaces".to_string(),
+                ))
+            }
+        };
+        let mut board = board.parse::<Board>()?;
+
+        let side = match side {
+            "b" => Piece::BLACK,
+            "w" => Piece::WHITE,
+            _ => return Err(invalid(format!("unknown side '{}'", side))),
+        };
+
+        match (phase, side, gap) {
+            ("j", _, None) => Game::new(board, side),
+            ("o", Piece::BLACK, None) => Ok(Game::opening(board)),
+            ("o", Piece::WHITE, Some(gap)) => {
+                let gap = gap.parse::<Square>()?;
+
+                // Put Black's piece back and take it out again, so the removal is checked
+                if board.get_piece(gap)? != Piece::EMPTY {
+                    return Err(invalid(format!("the gap at {} isn't empty", gap)));
+                }
+                board.set_piece(gap, Piece::BLACK)?;
+
+                let mut game = Game::opening(board);
+                game.remove(gap)?;
+                Ok(game)
+            }
+            ("o", Piece::WHITE, None) => Err(invalid(
+                "expected the square Black emptied when White is to remove".to_string(),
+            )),
+            ("o" | "j", _, Some(gap)) => Err(invalid(format!("unexpected square '{}'", gap))),
+            _ => Err(invalid(format!("unknown phase '{}'", phase))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Board, BoardBuilder, Game, KonaneError, Phase, Piece, Square, Turn};
+
+    fn round_trip(game: &Game) -> Game {
+        let parsed = game.to_notation().parse::<Game>().unwrap();
+
+        assert_eq!(parsed.board(), game.board());
+        assert_eq!(parsed.side_to_move(), game.side_to_move());
+        assert_eq!(parsed.phase(), game.phase());
+        assert_eq!(parsed.legal_removals(), game.legal_removals());
+        assert_eq!(parsed.legal_moves(), game.legal_moves());
+        assert_eq!(parsed.to_notation(), game.to_notation());
+
+        parsed
+    }
+
+    #[test]
+    fn round_trips_through_a_game() {
+        let mut game = Game::opening(BoardBuilder::new().size(6, 5).build().unwrap());
+        round_trip(&game);
+
+        game.remove((2, 2)).unwrap();
+        let parsed = round_trip(&game);
+        assert_eq!(parsed.history(), &[Turn::Removal(Square::new(2, 2))]);
+
+        game.remove((2, 3)).unwrap();
+        while game.result().is_none() {
+            round_trip(&game);
+            let jump = game.legal_moves()[game.ply() % game.legal_moves().len()];
+            game.play(jump).unwrap();
+        }
+
+        // A finished game reads back as finished
+        assert_eq!(round_trip(&game).result(), game.result());
+    }
+
+    #[test]
+    fn rejects_bad_notation() {
+        let error = |notation: &str| notation.parse::<Game>().unwrap_err();
+        let invalid = |reason: &str| KonaneError::InvalidNotation(reason.to_string());
+
+        assert_eq!(error("2x1 BW x j"), invalid("unknown side 'x'"));
+        assert_eq!(error("2x1 BW b z"), invalid("unknown phase 'z'"));
+        assert_eq!(error("2x1 BW b j a1"), invalid("unexpected square 'a1'"));
+        assert_eq!(
+            error("2x1 1W w o"),
+            invalid("expected the square Black emptied when White is to remove")
+        );
+        assert_eq!(error("2x1 1W w o 0,0"), invalid("'0,0' isn't a square"));
+        assert_eq!(error("2x1 BW w o a1"), invalid("the gap at a1 isn't empty"));
+        assert_eq!(
+            error("2x1 1W w o f1"),
+            KonaneError::OutOfBounds(Square::new(0, 5))
+        );
+
+        // Black can't have opened from the middle of an edge
+        let board = BoardBuilder::new().size(6, 6).build().unwrap();
+        let mut notation = board.to_notation().replacen("BWB", "B1B", 1);
+        notation.push_str(" w o b1");
+        assert!(notation.parse::<Game>().is_err());
+    }
+
+    #[test]
+    fn reads_positions_after_the_opening() {
+        let mut board = Board::create_empty();
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((0, 1), Piece::WHITE);
+
+        let game = Game::new(board.clone(), Piece::BLACK).unwrap();
+        let parsed = round_trip(&game);
+        assert_eq!(parsed.phase(), Phase::Jumping);
+        assert_eq!(parsed.ply(), 0);
+        assert_eq!(parsed.board(), &board);
+    }
+}
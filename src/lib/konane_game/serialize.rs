@@ -0,0 +1,149 @@
+//! Serde support for games, enabled with the `serde` feature.
+//!
+//! A game is written as the position it started from and every turn taken since, so that
+//! reading it back replays the turns and checks they're legal. The start holds its
+//! [board](crate::Board), the side to move, `"black"` or `"white"`, and the phase, `"opening"`
+//! or `"jumping"`. Each turn is either `{ "removal": "a1" }` or `{ "jump": "a3-a1" }`:
+//!
+//! ```json
+//! {
+//!   "start": {
+//!     "board": { "width": 4, "height": 4, "rows": ["BWBW", "WBWB", "BWBW", "WBWB"] },
+//!     "side_to_move": "black",
+//!     "phase": "opening"
+//!   },
+//!   "turns": [{ "removal": "a1" }, { "removal": "b1" }, { "jump": "a3-a1" }]
+//! }
+//! ```
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Game, Phase, Turn};
+use crate::{Board, Piece};
+
+/// The shape a game is written in.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GameShape {
+    start: StartShape,
+    turns: Vec<Turn>,
+}
+
+/// The position a game started from.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StartShape {
+    board: Board,
+    side_to_move: Piece,
+    phase: Phase,
+}
+
+impl Serialize for Game {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut start = self.clone();
+        while start.undo().is_ok() {}
+
+        GameShape {
+            start: StartShape {
+                board: start.board().clone(),
+                side_to_move: start.side_to_move(),
+                phase: start.phase(),
+            },
+            turns: self.history().to_vec(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Game {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Game, D::Error> {
+        let GameShape { start, turns } = GameShape::deserialize(deserializer)?;
+
+        let mut game = match (start.phase, start.side_to_move) {
+            (Phase::Opening, Piece::BLACK) => Game::opening(start.board),
+            (Phase::Opening, _) => {
+                return Err(D::Error::custom(
+                    "a game can only start its opening with Black to remove",
+                ))
+            }
+            (Phase::Jumping, side) => Game::new(start.board, side).map_err(D::Error::custom)?,
+        };
+
+        for turn in turns {
+            match turn {
+                Turn::Removal(square) => game.remove(square).map(|_| ()),
+                Turn::Jump(jump) => game.play(jump).map(|_| ()),
+            }
+            .map_err(D::Error::custom)?;
+        }
+
+        Ok(game)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{Board, BoardBuilder, Game, Piece, Square, Turn};
+
+    #[test]
+    fn round_trips_whole_games() {
+        let mut game = Game::opening(BoardBuilder::new().size(4, 4).build().unwrap());
+        game.remove((0, 0)).unwrap();
+        game.remove((0, 1)).unwrap();
+        let jump = game.legal_moves()[0];
+        game.play(jump).unwrap();
+
+        let value = serde_json::to_value(&game).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "start": {
+                    "board": { "width": 4, "height": 4, "rows": ["BWBW", "WBWB", "BWBW", "WBWB"] },
+                    "side_to_move": "black",
+                    "phase": "opening"
+                },
+                "turns": [{ "removal": "a1" }, { "removal": "b1" }, { "jump": "a3-a1" }]
+            })
+        );
+
+        let read = serde_json::from_value::<Game>(value).unwrap();
+        assert_eq!(read.board(), game.board());
+        assert_eq!(read.history(), game.history());
+        assert_eq!(read.side_to_move(), game.side_to_move());
+        assert_eq!(read.history()[0], Turn::Removal(Square::new(0, 0)));
+    }
+
+    #[test]
+    fn keeps_the_starting_position() {
+        let mut board = Board::create_empty();
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((0, 1), Piece::WHITE);
+
+        let game = Game::new(board, Piece::WHITE).unwrap();
+        let read = serde_json::from_str::<Game>(&serde_json::to_string(&game).unwrap()).unwrap();
+
+        assert_eq!(read.board(), game.board());
+        assert_eq!(read.side_to_move(), Piece::WHITE);
+        assert_eq!(read.result(), Some(Piece::BLACK));
+    }
+
+    #[test]
+    fn rejects_illegal_games() {
+        let board = serde_json::to_value(Board::default()).unwrap();
+        let game = |side: &str, phase: &str, turns| {
+            serde_json::from_value::<Game>(json!({
+                "start": { "board": board, "side_to_move": side, "phase": phase },
+                "turns": turns
+            }))
+        };
+
+        assert!(game("black", "opening", json!([{ "removal": "a1" }])).is_ok());
+        assert!(game("black", "opening", json!([{ "removal": "b2" }])).is_err());
+        assert!(game("black", "opening", json!([{ "jump": "a3-a1" }])).is_err());
+        assert!(game("white", "opening", json!([])).is_err());
+        assert!(game("empty", "jumping", json!([])).is_err());
+        assert!(game("black", "jumping", json!([{ "removal": "a1" }])).is_err());
+    }
+}
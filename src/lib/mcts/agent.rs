@@ -0,0 +1,410 @@
+use std::{
+    f64::consts::SQRT_2,
+    time::{Duration, Instant},
+};
+
+use super::{
+    playout::{playout, PlayoutPolicy},
+    rng::Rng,
+};
+use crate::{Board, Move, Piece};
+
+/// How many iterations to run if neither an iteration nor a time limit is given.
+const DEFAULT_ITERATIONS: u64 = 10_000;
+
+/// What the search found out about one of the moves at the root.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MoveStats {
+    /// The move.
+    pub jump: Move,
+    /// The number of playouts that started with this move.
+    pub visits: u32,
+    /// The number of those playouts won by the side to move at the root.
+    pub wins: u32,
+}
+
+impl MoveStats {
+    /// The fraction of playouts won, or zero if there weren't any.
+    pub fn win_rate(&self) -> f64 {
+        if self.visits == 0 {
+            0.0
+        } else {
+            self.wins as f64 / self.visits as f64
+        }
+    }
+}
+
+/// The outcome of a Monte Carlo search.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MctsResult {
+    /// The move tried most often, which is the one the search recommends.
+    pub best_move: Move,
+    /// The number of iterations run by this search, not counting any from earlier searches
+    /// that were reused.
+    pub iterations: u64,
+    /// Every move tried at the root, most visited first.
+    pub moves: Vec<MoveStats>,
+}
+
+/// A position in the search tree.
+#[derive(Clone, Debug)]
+struct Node {
+    key: u64,
+    /// The move that led here, which is `None` for the root.
+    jump: Option<Move>,
+    /// The side that played `jump`, whose point of view `wins` is counted from.
+    mover: Piece,
+    children: Vec<usize>,
+    untried: Vec<Move>,
+    visits: u32,
+    wins: u32,
+}
+
+impl Node {
+    fn new(board: &Board, mover: Piece, jump: Option<Move>) -> Node {
+        Node {
+            key: board.hash_key(mover.opponent()),
+            jump,
+            mover,
+            children: vec![],
+            untried: board.legal_moves(mover.opponent()),
+            visits: 0,
+            wins: 0,
+        }
+    }
+}
+
+/// A Monte Carlo tree search player using UCT to choose which moves to explore.
+///
+/// Each iteration follows the most promising moves down the tree, adds a new position to it,
+/// plays the game out to the end, and counts the result towards every position on the way. The
+/// tree is kept between searches, so when the next search starts from a position already in
+/// the tree, such as after the recommended move and a reply, its statistics are reused.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{Board, Direction, Mcts, Move, Piece};
+///
+/// let mut board = Board::create_empty();
+/// let _ = board.set_piece((0, 0), Piece::BLACK);
+/// let _ = board.set_piece((0, 1), Piece::WHITE);
+/// let _ = board.set_piece((0, 3), Piece::WHITE);
+/// let _ = board.set_piece((1, 3), Piece::BLACK);
+///
+/// let mut mcts = Mcts::new().iterations(500).seed(7);
+/// let result = mcts.search(&board, Piece::BLACK).unwrap();
+///
+/// // Jumping twice leaves White without a move
+/// assert_eq!(result.best_move, Move::new((0, 0), Direction::Right, 2));
+/// assert_eq!(result.moves[0].win_rate(), 1.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Mcts {
+    exploration: f64,
+    policy: PlayoutPolicy,
+    iterations: Option<u64>,
+    time: Option<Duration>,
+    rng: Rng,
+    nodes: Vec<Node>,
+}
+
+/// Explores with a constant of √2, plays random playouts, and runs 10,000 iterations.
+impl Default for Mcts {
+    fn default() -> Self {
+        Mcts {
+            exploration: SQRT_2,
+            policy: PlayoutPolicy::default(),
+            iterations: None,
+            time: None,
+            rng: Rng::new(0),
+            nodes: vec![],
+        }
+    }
+}
+
+impl Mcts {
+    /// Create a player with the default settings.
+    pub fn new() -> Mcts {
+        Mcts::default()
+    }
+
+    /// Weigh exploring rarely tried moves against exploiting those that have done well.
+    /// Higher values explore more.
+    pub fn exploration(mut self, exploration: f64) -> Mcts {
+        self.exploration = exploration;
+        self
+    }
+
+    /// Choose moves during playouts with `policy`.
+    pub fn playout(mut self, policy: PlayoutPolicy) -> Mcts {
+        self.policy = policy;
+        self
+    }
+
+    /// Run no more than `iterations` iterations per search.
+    pub fn iterations(mut self, iterations: u64) -> Mcts {
+        self.iterations = Some(iterations);
+        self
+    }
+
+    /// Stop searching once `time` has passed.
+    pub fn time(mut self, time: Duration) -> Mcts {
+        self.time = Some(time);
+        self
+    }
+
+    /// Seed the random numbers used to break ties and play out games, so that searches can be
+    /// repeated.
+    pub fn seed(mut self, seed: u64) -> Mcts {
+        self.rng = Rng::new(seed);
+        self
+    }
+
+    /// Search for the best move for `side`, reusing the tree from earlier searches if it
+    /// contains this position. At least one iteration is always run. Returns `None` if `side`
+    /// has no legal moves.
+    pub fn search(&mut self, board: &Board, side: Piece) -> Option<MctsResult> {
+        if board.legal_moves(side).is_empty() {
+            return None;
+        }
+
+        self.reuse_tree(board, side);
+
+        let deadline = self.time.map(|time| Instant::now() + time);
+        let max_iterations = match (self.iterations, deadline) {
+            (None, None) => Some(DEFAULT_ITERATIONS),
+            (iterations, _) => iterations,
+        };
+
+        let mut board = board.clone();
+        let mut iterations = 0;
+        loop {
+            self.iterate(&mut board);
+            iterations += 1;
+
+            if max_iterations.is_some_and(|max| iterations >= max)
+                || deadline.is_some_and(|deadline| Instant::now() >= deadline)
+            {
+                break;
+            }
+        }
+
+        let moves = self.root_moves();
+
+        Some(MctsResult {
+            best_move: moves.first()?.jump,
+            iterations,
+            moves,
+        })
+    }
+
+    /// Statistics for every move tried from the root of the tree, most visited first.
+    pub fn root_moves(&self) -> Vec<MoveStats> {
+        let Some(root) = self.nodes.first() else {
+            return vec![];
+        };
+
+        let mut moves = root
+            .children
+            .iter()
+            .map(|&child| {
+                let node = &self.nodes[child];
+                MoveStats {
+                    jump: node.jump.expect("only the root has no move"),
+                    visits: node.visits,
+                    wins: node.wins,
+                }
+            })
+            .collect::<Vec<_>>();
+        moves.sort_by(|a, b| b.visits.cmp(&a.visits).then(b.wins.cmp(&a.wins)));
+
+        moves
+    }
+
+    /// Throw away the tree, so that the next search starts afresh.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
+
+    /// Make the node for this position the root, keeping everything below it and dropping the
+    /// rest, or start a new tree if it isn't there.
+    fn reuse_tree(&mut self, board: &Board, side: Piece) {
+        let key = board.hash_key(side);
+
+        match self.nodes.iter().position(|node| node.key == key) {
+            Some(0) => {}
+            Some(index) => {
+                let mut nodes = vec![];
+                copy_subtree(&self.nodes, index, &mut nodes);
+                nodes[0].jump = None;
+
+                self.nodes = nodes;
+            }
+            None => self.nodes = vec![Node::new(board, side.opponent(), None)],
+        }
+    }
+
+    /// Select a path down the tree, expand it by one position, play the game out from there,
+    /// and count the result along the path.
+    fn iterate(&mut self, board: &mut Board) {
+        let mut path = vec![0];
+        let mut index = 0;
+
+        while self.nodes[index].untried.is_empty() && !self.nodes[index].children.is_empty() {
+            index = self.select_child(index);
+            path.push(index);
+
+            let jump = self.nodes[index].jump.expect("only the root has no move");
+            board.apply_move(jump).expect("moves in the tree are legal");
+        }
+
+        if !self.nodes[index].untried.is_empty() {
+            let untried = &mut self.nodes[index].untried;
+            let jump = untried.swap_remove(self.rng.below(untried.len()));
+            let mover = self.nodes[index].mover.opponent();
+
+            board
+                .apply_move(jump)
+                .expect("generated moves are always legal");
+
+            let child = self.nodes.len();
+            self.nodes.push(Node::new(board, mover, Some(jump)));
+            self.nodes[index].children.push(child);
+
+            index = child;
+            path.push(index);
+        }
+
+        let side = self.nodes[index].mover.opponent();
+        let winner = playout(board, side, self.policy, &mut self.rng);
+
+        for &index in &path {
+            let node = &mut self.nodes[index];
+            node.visits += 1;
+            if node.mover == winner {
+                node.wins += 1;
+            }
+        }
+
+        for _ in 1..path.len() {
+            board.unmake_move().expect("the move was applied above");
+        }
+    }
+
+    /// The child with the highest upper confidence bound.
+    fn select_child(&self, index: usize) -> usize {
+        let log_visits = (self.nodes[index].visits as f64).ln();
+        let bound = |child: usize| {
+            let node = &self.nodes[child];
+            let visits = node.visits as f64;
+
+            node.wins as f64 / visits + self.exploration * (log_visits / visits).sqrt()
+        };
+
+        self.nodes[index]
+            .children
+            .iter()
+            .copied()
+            .max_by(|&a, &b| bound(a).total_cmp(&bound(b)))
+            .expect("only called on nodes with children")
+    }
+}
+
+/// Copy the node at `index` and everything below it onto the end of `nodes`, returning the
+/// copy's index.
+fn copy_subtree(from: &[Node], index: usize, nodes: &mut Vec<Node>) -> usize {
+    let copy = nodes.len();
+    nodes.push(Node {
+        children: vec![],
+        ..from[index].clone()
+    });
+
+    for &child in &from[index].children {
+        let child = copy_subtree(from, child, nodes);
+        nodes[copy].children.push(child);
+    }
+
+    copy
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::{testing::opened, Board, Mcts, Piece, PlayoutPolicy};
+
+    #[test]
+    fn visits_add_up_to_the_iterations() {
+        let game = opened();
+        let result = Mcts::new()
+            .iterations(300)
+            .search(game.board(), Piece::BLACK)
+            .unwrap();
+
+        assert_eq!(result.iterations, 300);
+        assert_eq!(
+            result.moves.iter().map(|stats| stats.visits).sum::<u32>(),
+            300
+        );
+        assert_eq!(result.moves[0].jump, result.best_move);
+        assert!(result
+            .moves
+            .windows(2)
+            .all(|pair| pair[0].visits >= pair[1].visits));
+        assert!(game.legal_moves().contains(&result.best_move));
+    }
+
+    #[test]
+    fn reuses_the_tree_after_a_reply() {
+        let mut game = opened();
+        let mut mcts = Mcts::new().iterations(500);
+
+        let first = mcts.search(game.board(), Piece::BLACK).unwrap();
+        game.play(first.best_move).unwrap();
+        let reply = game.legal_moves()[0];
+        game.play(reply).unwrap();
+
+        let second = mcts.search(game.board(), Piece::BLACK).unwrap();
+        let visits = second.moves.iter().map(|stats| stats.visits).sum::<u32>();
+        assert!(visits > 500);
+
+        // A position that was never searched starts afresh
+        let mut board = Board::create_empty();
+        let _ = board.set_piece((0, 0), Piece::WHITE);
+        let _ = board.set_piece((0, 1), Piece::BLACK);
+
+        let fresh = mcts.search(&board, Piece::WHITE).unwrap();
+        assert_eq!(fresh.moves.len(), 1);
+        assert_eq!(fresh.moves[0].visits, 500);
+    }
+
+    #[test]
+    fn seeded_searches_repeat() {
+        let game = opened();
+        let search = || {
+            Mcts::new()
+                .iterations(200)
+                .seed(42)
+                .exploration(0.5)
+                .playout(PlayoutPolicy::Greedy)
+                .search(game.board(), Piece::BLACK)
+        };
+
+        assert_eq!(search(), search());
+    }
+
+    #[test]
+    fn honours_the_time_limit() {
+        let game = opened();
+        let start = Instant::now();
+
+        let result = Mcts::new()
+            .time(Duration::from_millis(50))
+            .search(game.board(), Piece::BLACK)
+            .unwrap();
+
+        assert!(result.iterations >= 1);
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+}
@@ -0,0 +1,6 @@
+mod agent;
+mod playout;
+mod rng;
+
+pub use agent::{Mcts, MctsResult, MoveStats};
+pub use playout::PlayoutPolicy;
@@ -0,0 +1,106 @@
+use super::rng::Rng;
+use crate::{Board, Piece};
+
+/// How moves are chosen when a playout runs a game to the end.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PlayoutPolicy {
+    /// Play any legal move, each as likely as any other.
+    #[default]
+    Random,
+    /// Play a move that leaves the opponent with as few replies as possible, choosing at
+    /// random between equally good moves. Playouts are more realistic, but much slower.
+    Greedy,
+}
+
+/// Play `board` out to the end of the game with `side` to move, returning the winner. The
+/// board is left as it was found.
+pub(super) fn playout(
+    board: &mut Board,
+    side: Piece,
+    policy: PlayoutPolicy,
+    rng: &mut Rng,
+) -> Piece {
+    let mut side = side;
+    let mut played = 0;
+
+    loop {
+        let moves = board.legal_moves(side);
+        if moves.is_empty() {
+            break;
+        }
+
+        let jump = match policy {
+            PlayoutPolicy::Random => moves[rng.below(moves.len())],
+            PlayoutPolicy::Greedy => {
+                let replies = moves
+                    .iter()
+                    .map(|&jump| {
+                        board
+                            .apply_move(jump)
+                            .expect("generated moves are always legal");
+                        let replies = board.legal_moves(side.opponent()).len();
+                        board.unmake_move().expect("the move was just applied");
+
+                        replies
+                    })
+                    .collect::<Vec<_>>();
+
+                let fewest = replies.iter().min().copied().unwrap_or_default();
+                let best = (0..moves.len())
+                    .filter(|&index| replies[index] == fewest)
+                    .collect::<Vec<_>>();
+
+                moves[best[rng.below(best.len())]]
+            }
+        };
+
+        board
+            .apply_move(jump)
+            .expect("generated moves are always legal");
+        played += 1;
+        side = side.opponent();
+    }
+
+    for _ in 0..played {
+        board
+            .unmake_move()
+            .expect("the move was applied during the playout");
+    }
+
+    side.opponent()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{playout, PlayoutPolicy};
+    use crate::{mcts::rng::Rng, testing::opened, Board, Piece};
+
+    #[test]
+    fn playouts_leave_the_board_alone() {
+        let game = opened();
+        let mut board = game.board().clone();
+        let mut rng = Rng::new(1);
+
+        for policy in [PlayoutPolicy::Random, PlayoutPolicy::Greedy] {
+            let winner = playout(&mut board, Piece::BLACK, policy, &mut rng);
+
+            assert_ne!(winner, Piece::EMPTY);
+            assert_eq!(&board, game.board());
+            assert_eq!(board.applied_moves().count(), 0);
+        }
+    }
+
+    #[test]
+    fn stuck_side_loses() {
+        let mut board = Board::create_empty();
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+
+        let winner = playout(
+            &mut board,
+            Piece::BLACK,
+            PlayoutPolicy::Random,
+            &mut Rng::new(0),
+        );
+        assert_eq!(winner, Piece::WHITE);
+    }
+}
@@ -0,0 +1,25 @@
+use crate::splitmix::splitmix64;
+
+/// A small, seedable SplitMix64 generator. Playouts only need numbers that look random, and a
+/// fixed seed makes every search repeatable.
+#[derive(Clone, Debug)]
+pub(crate) struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        let (state, output) = splitmix64(self.state);
+        self.state = state;
+        output
+    }
+
+    /// A number from `0` up to, but not including, `bound`, which mustn't be zero.
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
@@ -1,4 +1,59 @@
+mod cgt;
+mod encoding;
+mod error;
+mod evaluation;
 mod konane_board;
+mod konane_game;
+mod mcts;
+mod perft;
+mod record;
+mod search;
+mod splitmix;
+#[cfg(test)]
+mod testing;
 
+pub use cgt::game_value;
+pub use cgt::Dyadic;
+pub use cgt::GameValue;
+pub use cgt::ValueTable;
+pub use encoding::EncodedPosition;
+pub use encoding::PositionReader;
+pub use encoding::PositionWriter;
+pub use error::KonaneError;
+pub use evaluation::Evaluator;
+pub use evaluation::Mobility;
+pub use evaluation::SafeMoves;
+pub use evaluation::Weighted;
 pub use konane_board::Board;
+pub use konane_board::BoardBuilder;
+pub use konane_board::Direction;
+pub use konane_board::Move;
 pub use konane_board::Piece;
+pub use konane_board::Setup;
+pub use konane_board::Square;
+pub use konane_game::Game;
+pub use konane_game::Phase;
+pub use konane_game::Turn;
+pub use mcts::Mcts;
+pub use mcts::MctsResult;
+pub use mcts::MoveStats;
+pub use mcts::PlayoutPolicy;
+pub use perft::divide;
+pub use perft::divide_game;
+pub use perft::perft;
+pub use perft::perft_game;
+pub use record::GameRecord;
+pub use search::best_move;
+pub use search::iterative_deepening;
+pub use search::Bound;
+pub use search::Outcome;
+pub use search::SearchLimits;
+pub use search::SearchResult;
+pub use search::Searcher;
+pub use search::Solution;
+pub use search::Solver;
+pub use search::StopFlag;
+pub use search::TableEntry;
+pub use search::TableStats;
+pub use search::TranspositionTable;
+pub use search::WIN_SCORE;
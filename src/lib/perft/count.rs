@@ -0,0 +1,372 @@
+use crate::{Board, Game, Move, Phase, Piece, Turn};
+
+/// Count every line of play `depth` moves long, starting with `side` to move. Lines that end
+/// early because a player is stuck aren't counted. Comparing these counts against known values
+/// is a thorough check of move generation.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{perft, Game, Piece};
+///
+/// let mut game = Game::default();
+/// game.remove((0, 0)).unwrap();
+/// game.remove((0, 1)).unwrap();
+///
+/// // Only the piece at (2, 0) can jump, into the empty corner
+/// assert_eq!(perft(game.board(), Piece::BLACK, 0), 1);
+/// assert_eq!(perft(game.board(), Piece::BLACK, 1), 1);
+/// ```
+pub fn perft(board: &Board, side: Piece, depth: usize) -> u64 {
+    count_jumps(&mut board.clone(), side, depth)
+}
+
+/// Like [`perft`], but counting the lines that start with each legal move separately, so that
+/// a wrong total can be traced to the move responsible. There's nothing to break down at depth
+/// zero, so no moves are listed.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{divide, perft, Game, Piece};
+///
+/// let mut game = Game::default();
+/// game.remove((2, 2)).unwrap();
+/// game.remove((2, 3)).unwrap();
+///
+/// let counts = divide(game.board(), Piece::BLACK, 3);
+/// let total = counts.iter().map(|(_, count)| count).sum::<u64>();
+///
+/// assert_eq!(counts.len(), game.legal_moves().len());
+/// assert_eq!(total, perft(game.board(), Piece::BLACK, 3));
+/// ```
+pub fn divide(board: &Board, side: Piece, depth: usize) -> Vec<(Move, u64)> {
+    if depth == 0 {
+        return vec![];
+    }
+    let mut board = board.clone();
+
+    board
+        .legal_moves(side)
+        .into_iter()
+        .map(|jump| {
+            board
+                .apply_move(jump)
+                .expect("generated moves are always legal");
+            let count = count_jumps(&mut board, side.opponent(), depth - 1);
+            board.unmake_move().expect("the move was just applied");
+
+            (jump, count)
+        })
+        .collect()
+}
+
+/// Like [`perft`], but counting every turn of a game, including removals in the opening.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{perft_game, Game};
+///
+/// // Black may remove from two corners or two points in the centre, and White then has two
+/// // pieces next to a corner or four next to the centre to choose from
+/// assert_eq!(perft_game(&Game::default(), 1), 4);
+/// assert_eq!(perft_game(&Game::default(), 2), 12);
+/// ```
+pub fn perft_game(game: &Game, depth: usize) -> u64 {
+    count_turns(&mut game.clone(), depth)
+}
+
+/// Like [`perft_game`], but counting the lines that start with each legal turn separately.
+/// Nothing is listed at depth zero.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{divide_game, perft_game, Game};
+///
+/// let counts = divide_game(&Game::default(), 2);
+/// assert_eq!(counts.len(), 4);
+/// assert_eq!(counts.iter().map(|(_, count)| count).sum::<u64>(), 12);
+///
+/// assert!(divide_game(&Game::default(), 0).is_empty());
+/// assert_eq!(perft_game(&Game::default(), 0), 1);
+/// ```
+pub fn divide_game(game: &Game, depth: usize) -> Vec<(Turn, u64)> {
+    if depth == 0 {
+        return vec![];
+    }
+    let mut game = game.clone();
+
+    legal_turns(&game)
+        .into_iter()
+        .map(|turn| {
+            take(&mut game, turn);
+            let count = count_turns(&mut game, depth - 1);
+            game.undo().expect("the turn was just taken");
+
+            (turn, count)
+        })
+        .collect()
+}
+
+fn count_jumps(board: &mut Board, side: Piece, depth: usize) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    let moves = board.legal_moves(side);
+    if depth == 1 {
+        return moves.len() as u64;
+    }
+
+    moves
+        .into_iter()
+        .map(|jump| {
+            board
+                .apply_move(jump)
+                .expect("generated moves are always legal");
+            let count = count_jumps(board, side.opponent(), depth - 1);
+            board.unmake_move().expect("the move was just applied");
+
+            count
+        })
+        .sum()
+}
+
+fn count_turns(game: &mut Game, depth: usize) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    if game.phase() == Phase::Jumping {
+        return count_jumps(&mut game.board().clone(), game.side_to_move(), depth);
+    }
+
+    legal_turns(game)
+        .into_iter()
+        .map(|turn| {
+            take(game, turn);
+            let count = count_turns(game, depth - 1);
+            game.undo().expect("the turn was just taken");
+
+            count
+        })
+        .sum()
+}
+
+/// Every turn the player to move may take, whichever phase the game is in.
+fn legal_turns(game: &Game) -> Vec<Turn> {
+    match game.phase() {
+        Phase::Opening => game
+            .legal_removals()
+            .into_iter()
+            .map(Turn::Removal)
+            .collect(),
+        Phase::Jumping => game.legal_moves().into_iter().map(Turn::Jump).collect(),
+    }
+}
+
+fn take(game: &mut Game, turn: Turn) {
+    match turn {
+        Turn::Removal(square) => {
+            game.remove(square).expect("listed removals are legal");
+        }
+        Turn::Jump(jump) => {
+            game.play(jump).expect("generated moves are always legal");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{perft, perft_game, Board, BoardBuilder, Game, Piece};
+
+    /// Counts for the standard starting position on square boards of each size, from the
+    /// opening onwards, indexed by depth starting at zero. They were first worked out by this
+    /// module and are checked against [`brute_force`], which shares none of its code.
+    const STANDARD: [(usize, &[u64]); 4] = [
+        (4, &[1, 4, 12, 16, 52, 120, 376, 972, 2808]),
+        (6, &[1, 4, 12, 28, 156, 668, 4192, 22676]),
+        (8, &[1, 4, 12, 28, 172, 892, 7124]),
+        (10, &[1, 4, 12, 28, 172, 984, 8596]),
+    ];
+
+    #[test]
+    fn standard_starting_positions() {
+        for (size, counts) in STANDARD {
+            let game = Game::opening(BoardBuilder::new().size(size, size).build().unwrap());
+
+            for (depth, &count) in counts.iter().enumerate() {
+                assert_eq!(
+                    perft_game(&game, depth),
+                    count,
+                    "{}x{} at depth {}",
+                    size,
+                    size,
+                    depth
+                );
+            }
+        }
+    }
+
+    /// Count the lines of play from the standard opening on a `size` square board, on a plain
+    /// grid of points with the rules written out again, without using the board or the game.
+    fn brute_force(size: usize, depth: usize) -> u64 {
+        // Black is 1 and starts in the top left corner, White is 2, and empty points are 0
+        let grid = (0..size)
+            .map(|row| (0..size).map(|col| 1 + (row + col) as u8 % 2).collect())
+            .collect::<Vec<Vec<u8>>>();
+
+        let last = size - 1;
+        let mut openings = vec![(0, 0), (0, last), (last, 0), (last, last)];
+        for row in [last / 2, size / 2] {
+            for col in [last / 2, size / 2] {
+                openings.push((row, col));
+            }
+        }
+        openings.sort();
+        openings.dedup();
+
+        if depth == 0 {
+            return 1;
+        }
+
+        let mut count = 0;
+        for (row, col) in openings {
+            if grid[row][col] != 1 {
+                continue;
+            }
+            let mut grid = grid.clone();
+            grid[row][col] = 0;
+            if depth == 1 {
+                count += 1;
+                continue;
+            }
+
+            let neighbours =
+                [(-1, 0), (1, 0), (0, -1), (0, 1)]
+                    .into_iter()
+                    .filter_map(|(down, right)| {
+                        let next = (
+                            row.checked_add_signed(down)?,
+                            col.checked_add_signed(right)?,
+                        );
+                        (next.0 < size && next.1 < size).then_some(next)
+                    });
+            for (row, col) in neighbours {
+                if grid[row][col] == 2 {
+                    let mut grid = grid.clone();
+                    grid[row][col] = 0;
+                    count += brute_force_jumps(&mut grid, 1, depth - 2);
+                }
+            }
+        }
+
+        count
+    }
+
+    fn brute_force_jumps(grid: &mut [Vec<u8>], side: u8, depth: usize) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+
+        let size = grid.len() as isize;
+        let mut count = 0;
+        for row in 0..size {
+            for col in 0..size {
+                if grid[row as usize][col as usize] != side {
+                    continue;
+                }
+
+                for (down, right) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                    let mut captured = vec![];
+                    let (mut to_row, mut to_col) = (row, col);
+
+                    loop {
+                        let (over_row, over_col) = (to_row + down, to_col + right);
+                        let (land_row, land_col) = (over_row + down, over_col + right);
+                        if land_row < 0 || land_row >= size || land_col < 0 || land_col >= size {
+                            break;
+                        }
+                        let (over, land) = (
+                            (over_row as usize, over_col as usize),
+                            (land_row as usize, land_col as usize),
+                        );
+                        if grid[over.0][over.1] != 3 - side || grid[land.0][land.1] != 0 {
+                            break;
+                        }
+
+                        captured.push(over);
+                        (to_row, to_col) = (land_row, land_col);
+
+                        let mut after = grid.to_vec();
+                        after[row as usize][col as usize] = 0;
+                        for &(row, col) in &captured {
+                            after[row][col] = 0;
+                        }
+                        after[land.0][land.1] = side;
+                        count += brute_force_jumps(&mut after, 3 - side, depth - 1);
+                    }
+                }
+            }
+        }
+
+        count
+    }
+
+    #[test]
+    fn standard_counts_match_brute_force() {
+        for (size, counts) in STANDARD {
+            for (depth, &count) in counts.iter().enumerate() {
+                assert_eq!(
+                    brute_force(size, depth),
+                    count,
+                    "{}x{} at depth {}",
+                    size,
+                    size,
+                    depth
+                );
+            }
+        }
+    }
+
+    /// The same count, generating moves by trying every jump from every point instead of with
+    /// bitboards.
+    fn scanned_perft(board: &mut Board, side: Piece, depth: usize) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+
+        let mut count = 0;
+        for row in 0..board.height() {
+            for col in 0..board.width() {
+                if board.get_piece((row, col)) != Ok(side) {
+                    continue;
+                }
+
+                for jump in board.moves_from((row, col)).unwrap() {
+                    board.apply_move(jump).unwrap();
+                    count += scanned_perft(board, side.opponent(), depth - 1);
+                    board.unmake_move().unwrap();
+                }
+            }
+        }
+
+        count
+    }
+
+    #[test]
+    fn matches_scanning_every_point() {
+        let mut game = Game::opening(BoardBuilder::new().size(8, 8).build().unwrap());
+        game.remove((3, 3)).unwrap();
+        game.remove((3, 4)).unwrap();
+        let mut board = game.board().clone();
+
+        for depth in 0..=4 {
+            assert_eq!(
+                perft(&board, Piece::BLACK, depth),
+                scanned_perft(&mut board, Piece::BLACK, depth)
+            );
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod count;
+
+pub use count::{divide, divide_game, perft, perft_game};
@@ -0,0 +1,302 @@
+use std::fmt::Display;
+
+use crate::{BoardBuilder, Game, KonaneError, Phase, Piece, Turn};
+
+/// The widest a line of turns is written.
+const LINE_WIDTH: usize = 80;
+
+/// A game written down in the style of chess's Portable Game Notation, so that it can be saved
+/// and replayed later.
+///
+/// A record starts with tags, one per line, such as `[Black "Keola"]`. Every record has the
+/// tags `Black`, `White`, `Date`, `Size`, `TimeControl` and `Result`, in that order, with `?`
+/// or `-` for anything unknown, and may have any others after them. `Size` is the board's
+/// width and height, such as `6x6`. `Result` is `1-0` if Black won, `0-1` if White won, or `*`
+/// if the game isn't finished. Games that don't start from the standard opening have a
+/// `Position` tag holding the [notation](Game::to_notation) of the starting position.
+///
+/// A blank line follows the tags, then the turns, numbered in pairs like `1. d4 d3`, and then
+/// the result again. A removal is the [square](crate::Square) emptied, such as `d4`, and a jump
+/// is written like any other [move](crate::Move), such as `c3-c5-c7`.
+///
+/// Records are read with [`str::parse`], which replays every turn and points to the line and
+/// column of anything that can't be read or isn't legal.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{Game, GameRecord};
+///
+/// let mut game = Game::default();
+/// game.remove((0, 0)).unwrap();
+/// game.remove((0, 1)).unwrap();
+/// let jump = game.legal_moves()[0];
+/// game.play(jump).unwrap();
+///
+/// let mut record = GameRecord::from_game(&game);
+/// record.set_tag("Black", "Keola").unwrap();
+///
+/// let text = record.to_string();
+/// assert!(text.starts_with("[Black \"Keola\"]\n[White \"?\"]\n"));
+/// assert!(text.ends_with("\n\n1. a1 b1 2. a3-a1 *\n"));
+///
+/// let read = text.parse::<GameRecord>().unwrap();
+/// assert_eq!(read, record);
+/// assert_eq!(read.to_game().unwrap().board(), game.board());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GameRecord {
+    tags: Vec<(String, String)>,
+    turns: Vec<Turn>,
+}
+
+impl GameRecord {
+    /// Record every turn taken in `game`, along with its board size, result and, if it didn't
+    /// start from the standard opening, its starting position.
+    pub fn from_game(game: &Game) -> GameRecord {
+        let (width, height) = (game.board().width(), game.board().height());
+        let result = result_name(game);
+
+        let mut record = GameRecord {
+            tags: vec![],
+            turns: game.history().to_vec(),
+        };
+        for (name, value) in [
+            ("Black", "?"),
+            ("White", "?"),
+            ("Date", "????.??.??"),
+            ("Size", &format!("{}x{}", width, height)),
+            ("TimeControl", "-"),
+            ("Result", result),
+        ] {
+            record
+                .set_tag(name, value)
+                .expect("the standard tags are always valid");
+        }
+
+        let mut start = game.clone();
+        while start.undo().is_ok() {}
+
+        let standard = BoardBuilder::new()
+            .size(width, height)
+            .build()
+            .expect("the board already has this size");
+        if start.phase() != Phase::Opening || start.board() != &standard {
+            record
+                .set_tag("Position", &start.to_notation())
+                .expect("a position's notation is always a valid value");
+        }
+
+        record
+    }
+
+    /// Replay the record from its starting position, returning the game it ends in. The
+    /// `Result` tag, if there is one, has to agree with how the game ends.
+    pub fn to_game(&self) -> Result<Game, KonaneError> {
+        let mut game = self.start()?;
+        for &turn in &self.turns {
+            take(&mut game, turn)?;
+        }
+
+        if let Some(result) = self.tag("Result") {
+            check_result(result, &game).map_err(KonaneError::InvalidNotation)?;
+        }
+        Ok(game)
+    }
+
+    /// The value of the tag called `name`, if the record has it.
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Set the tag called `name`, replacing its value if the record already has it and adding
+    /// it to the end if not. So that the record can be read back, the name can only hold
+    /// letters, digits and `_`, and the value can't hold control characters such as line
+    /// breaks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use konane_engine::{Game, GameRecord, KonaneError};
+    ///
+    /// let mut record = GameRecord::from_game(&Game::default());
+    /// record.set_tag("Event", "Club night").unwrap();
+    /// assert_eq!(record.tag("Event"), Some("Club night"));
+    ///
+    /// assert_eq!(
+    ///     record.set_tag("Bad Name", "?"),
+    ///     Err(KonaneError::InvalidNotation(
+    ///         "the tag name 'Bad Name' can only hold letters, digits and '_'".to_string()
+    ///     ))
+    /// );
+    /// ```
+    pub fn set_tag(&mut self, name: &str, value: &str) -> Result<(), KonaneError> {
+        check_tag(name, value).map_err(KonaneError::InvalidNotation)?;
+
+        match self.tags.iter_mut().find(|(tag, _)| tag == name) {
+            Some((_, old)) => *old = value.to_string(),
+            None => self.tags.push((name.to_string(), value.to_string())),
+        }
+        Ok(())
+    }
+
+    /// Every tag, in the order they're written.
+    pub fn tags(&self) -> &[(String, String)] {
+        &self.tags
+    }
+
+    /// Every turn taken, oldest first.
+    pub fn turns(&self) -> &[Turn] {
+        &self.turns
+    }
+
+    /// Build a record from tags and turns that have already been read.
+    pub(super) fn from_parts(tags: Vec<(String, String)>, turns: Vec<Turn>) -> GameRecord {
+        GameRecord { tags, turns }
+    }
+
+    /// The game before any of the record's turns, from its `Position` tag, or the standard
+    /// opening on a board of its `Size`.
+    pub(super) fn start(&self) -> Result<Game, KonaneError> {
+        let size = match self.tag("Size") {
+            Some(size) => Some(
+                size.split_once('x')
+                    .and_then(|(width, height)| Some((width.parse().ok()?, height.parse().ok()?)))
+                    .ok_or_else(|| {
+                        KonaneError::InvalidNotation(format!(
+                            "expected a size like 6x6 but found '{}'",
+                            size
+                        ))
+                    })?,
+            ),
+            None => None,
+        };
+
+        let start = match self.tag("Position") {
+            Some(position) => position.parse::<Game>()?,
+            None => {
+                let (width, height) = size.unwrap_or((6, 6));
+                Game::opening(BoardBuilder::new().size(width, height).build()?)
+            }
+        };
+
+        let board = start.board();
+        if size.is_some_and(|size| size != (board.width(), board.height())) {
+            return Err(KonaneError::InvalidNotation(
+                "the size doesn't match the position".to_string(),
+            ));
+        }
+
+        Ok(start)
+    }
+}
+
+/// Take `turn` in `game`, whichever kind of turn it is.
+pub(super) fn take(game: &mut Game, turn: Turn) -> Result<(), KonaneError> {
+    match turn {
+        Turn::Removal(square) => game.remove(square).map(|_| ()),
+        Turn::Jump(jump) => game.play(jump).map(|_| ()),
+    }
+}
+
+/// Check that a tag can be written and read back, explaining why not if it can't.
+pub(super) fn check_tag(name: &str, value: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("a tag needs a name".to_string());
+    }
+    if !name
+        .chars()
+        .all(|char| char.is_alphanumeric() || char == '_')
+    {
+        return Err(format!(
+            "the tag name '{}' can only hold letters, digits and '_'",
+            name
+        ));
+    }
+    if value.chars().any(char::is_control) {
+        return Err(format!(
+            "the value of the {} tag can't hold control characters",
+            name
+        ));
+    }
+
+    Ok(())
+}
+
+/// How a record writes the result of `game`.
+pub(super) fn result_name(game: &Game) -> &'static str {
+    match game.result() {
+        Some(Piece::BLACK) => "1-0",
+        Some(_) => "0-1",
+        None => "*",
+    }
+}
+
+/// Check a result written in a record against how `game` actually ended, explaining any
+/// difference.
+pub(super) fn check_result(result: &str, game: &Game) -> Result<(), String> {
+    if result == result_name(game) {
+        return Ok(());
+    }
+
+    let actual = match game.result() {
+        Some(Piece::BLACK) => "Black won",
+        Some(_) => "White won",
+        None => "the game isn't over",
+    };
+    Err(format!("the result is {} but {}", result, actual))
+}
+
+/// The name of a turn, as written in a record.
+pub(super) fn turn_name(turn: Turn) -> String {
+    match turn {
+        Turn::Removal(square) => square.to_string(),
+        Turn::Jump(jump) => jump.to_string(),
+    }
+}
+
+impl Display for GameRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (name, value) in &self.tags {
+            let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+            writeln!(f, "[{} \"{}\"]", name, value)?;
+        }
+        writeln!(f)?;
+
+        // Number the turns from wherever the starting position leaves off, so that Black's
+        // turns always start a pair
+        let offset = match self.start() {
+            Ok(start) if start.side_to_move() == Piece::WHITE => 1,
+            _ => 0,
+        };
+
+        let mut words = vec![];
+        for (index, &turn) in self.turns.iter().enumerate() {
+            let ply = index + offset;
+            if ply % 2 == 0 {
+                words.push(format!("{}.", ply / 2 + 1));
+            } else if index == 0 {
+                words.push(format!("{}...", ply / 2 + 1));
+            }
+            words.push(turn_name(turn));
+        }
+        words.push(self.tag("Result").unwrap_or("*").to_string());
+
+        let mut line = String::new();
+        for word in words {
+            if !line.is_empty() && line.len() + 1 + word.len() > LINE_WIDTH {
+                writeln!(f, "{}", line)?;
+                line.clear();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(&word);
+        }
+
+        writeln!(f, "{}", line)
+    }
+}
@@ -0,0 +1,6 @@
+mod game_record;
+mod parse;
+#[cfg(feature = "serde")]
+mod serialize;
+
+pub use game_record::GameRecord;
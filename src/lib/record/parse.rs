@@ -0,0 +1,441 @@
+use std::str::FromStr;
+
+use super::game_record::{check_result, take, GameRecord};
+use crate::{Game, KonaneError, Move, Square, Turn};
+
+/// Reads a record written by [`GameRecord`]'s `Display`, replaying every turn to check it's
+/// legal.
+///
+/// Tags must come before any turns, blank lines are ignored, and turns may be split across
+/// lines however they like. Move numbers are skipped rather than checked. The result at the
+/// end may be left off, but it and the `Result` tag both have to agree with how the game
+/// ends, so a finished game can't be marked `*` and an unfinished one can't be given a
+/// winner.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{GameRecord, KonaneError, Square, Turn};
+///
+/// let record = "[Size \"4x4\"]\n\n1. a1 b1\n2. a3-a1 *".parse::<GameRecord>().unwrap();
+/// assert_eq!(record.tag("Size"), Some("4x4"));
+/// assert_eq!(record.turns()[0], Turn::Removal(Square::new(0, 0)));
+///
+/// // White removing a2 left nothing for a3 to jump over
+/// assert_eq!(
+///     "1. a1 a2 2. a3-a1".parse::<GameRecord>(),
+///     Err(KonaneError::InvalidRecord {
+///         line: 1,
+///         column: 13,
+///         reason: "there is no enemy piece at a2 to jump over".to_string()
+///     })
+/// );
+/// ```
+impl FromStr for GameRecord {
+    type Err = KonaneError;
+
+    fn from_str(text: &str) -> Result<GameRecord, KonaneError> {
+        let mut reader = Reader::default();
+
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let trimmed = line.trim_start();
+            let indent = line.chars().count() - trimmed.chars().count();
+
+            if trimmed.is_empty() {
+                continue;
+            }
+            if trimmed.starts_with('[') {
+                reader.read_tag(trimmed, line_number, indent + 1)?;
+                continue;
+            }
+
+            let mut start = None;
+            for (offset, char) in line.char_indices().chain([(line.len(), ' ')]) {
+                match (char.is_whitespace(), start) {
+                    (false, None) => start = Some(offset),
+                    (true, Some(from)) => {
+                        let column = line[..from].chars().count() + 1;
+                        reader.read_word(&line[from..offset], line_number, column)?;
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        reader.game(1, 1)?;
+        let game = reader.game.as_ref().expect("the game was just set up");
+        if let Some(index) = reader.tags.iter().position(|(tag, _)| tag == "Result") {
+            check_result(&reader.tags[index].1, game).map_err(|reason| {
+                let (line, column) = reader.places[index];
+                KonaneError::InvalidRecord {
+                    line,
+                    column,
+                    reason,
+                }
+            })?;
+        }
+
+        Ok(GameRecord::from_parts(reader.tags, reader.turns))
+    }
+}
+
+/// What has been read of a record so far.
+#[derive(Default)]
+struct Reader {
+    tags: Vec<(String, String)>,
+    /// Where each tag's value starts.
+    places: Vec<(usize, usize)>,
+    turns: Vec<Turn>,
+    /// The game so far, once the tags are finished and the turns have started.
+    game: Option<Game>,
+    finished: bool,
+}
+
+impl Reader {
+    /// Read a tag line such as `[Black "Keola"]`, which starts at `column`.
+    fn read_tag(&mut self, text: &str, line: usize, column: usize) -> Result<(), KonaneError> {
+        let error = |offset: usize, reason: &str| KonaneError::InvalidRecord {
+            line,
+            column: column + offset,
+            reason: reason.to_string(),
+        };
+
+        if self.game.is_some() {
+            return Err(error(0, "tags must come before the turns"));
+        }
+
+        let chars = text.chars().collect::<Vec<_>>();
+        let name_end = (1..chars.len())
+            .find(|&index| !chars[index].is_alphanumeric() && chars[index] != '_')
+            .unwrap_or(chars.len());
+        if name_end == 1 {
+            return Err(error(1, "expected the name of a tag"));
+        }
+        let name = chars[1..name_end].iter().collect::<String>();
+
+        let quote = (name_end..chars.len())
+            .find(|&index| !chars[index].is_whitespace())
+            .filter(|&index| index > name_end && chars[index] == '"')
+            .ok_or_else(|| error(name_end, "expected a space and then a quoted value"))?;
+
+        let mut value = String::new();
+        let mut index = quote + 1;
+        loop {
+            match chars.get(index) {
+                None => return Err(error(index, "the value is missing its closing quote")),
+                Some('"') => break,
+                Some('\\') if matches!(chars.get(index + 1), Some('"' | '\\')) => {
+                    value.push(chars[index + 1]);
+                    index += 2;
+                }
+                Some(&char) => {
+                    value.push(char);
+                    index += 1;
+                }
+            }
+        }
+
+        let rest = chars[index + 1..].iter().collect::<String>();
+        if rest.trim_end() != "]" {
+            return Err(error(index + 1, "expected the tag to end with ']'"));
+        }
+        if self.tags.iter().any(|(tag, _)| *tag == name) {
+            return Err(error(1, &format!("the {} tag appears twice", name)));
+        }
+
+        self.tags.push((name, value));
+        self.places.push((line, column + quote));
+        Ok(())
+    }
+
+    /// Read a word from the turns, which is a move number, a turn or the result.
+    fn read_word(&mut self, word: &str, line: usize, column: usize) -> Result<(), KonaneError> {
+        let error = |reason: String| KonaneError::InvalidRecord {
+            line,
+            column,
+            reason,
+        };
+
+        self.game(line, column)?;
+        if self.finished {
+            return Err(error("nothing may follow the result".to_string()));
+        }
+
+        let digits = word.trim_end_matches('.');
+        if digits.len() < word.len()
+            && !digits.is_empty()
+            && digits.bytes().all(|byte| byte.is_ascii_digit())
+        {
+            return Ok(());
+        }
+
+        if let "1-0" | "0-1" | "*" = word {
+            if let Some(tag) = self.tags.iter().find(|(tag, _)| tag == "Result") {
+                if tag.1 != word {
+                    return Err(error(format!(
+                        "the result {} doesn't match the Result tag, {}",
+                        word, tag.1
+                    )));
+                }
+            }
+
+            let game = self.game.as_ref().expect("the game was set up above");
+            check_result(word, game).map_err(error)?;
+
+            self.finished = true;
+            return Ok(());
+        }
+
+        let turn = if word.contains('-') {
+            let jump = word.parse::<Move>().map_err(|reason| match reason {
+                KonaneError::InvalidNotation(reason) => error(reason),
+                reason => error(reason.to_string()),
+            })?;
+            Turn::Jump(jump)
+        } else {
+            let square = word
+                .parse::<Square>()
+                .map_err(|_| error(format!("'{}' isn't a turn", word)))?;
+            Turn::Removal(square)
+        };
+
+        let game = self.game.as_mut().expect("the game was set up above");
+        take(game, turn).map_err(|reason| error(reason.to_string()))?;
+        self.turns.push(turn);
+        Ok(())
+    }
+
+    /// The game being replayed, set up from the tags the first time it's needed. Problems with
+    /// the starting position are reported at the `Position` or `Size` tag, or at `line` and
+    /// `column` if there's neither.
+    fn game(&mut self, line: usize, column: usize) -> Result<&mut Game, KonaneError> {
+        if self.game.is_none() {
+            let record = GameRecord::from_parts(self.tags.clone(), vec![]);
+            let start = record.start().map_err(|reason| {
+                let (line, column) = ["Position", "Size"]
+                    .into_iter()
+                    .find_map(|name| {
+                        let index = self.tags.iter().position(|(tag, _)| tag == name)?;
+                        Some(self.places[index])
+                    })
+                    .unwrap_or((line, column));
+
+                KonaneError::InvalidRecord {
+                    line,
+                    column,
+                    reason: reason.to_string(),
+                }
+            })?;
+
+            self.game = Some(start);
+        }
+
+        Ok(self.game.as_mut().expect("the game was just set up"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Board, BoardBuilder, Game, GameRecord, KonaneError, Piece};
+
+    fn error(text: &str) -> (usize, usize, String) {
+        match text.parse::<GameRecord>() {
+            Err(KonaneError::InvalidRecord {
+                line,
+                column,
+                reason,
+            }) => (line, column, reason),
+            other => panic!("expected an invalid record but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn round_trips_whole_games() {
+        for (width, height) in [(4, 4), (6, 6), (8, 5)] {
+            let mut game = Game::opening(BoardBuilder::new().size(width, height).build().unwrap());
+            let removal = game.legal_removals()[game.legal_removals().len() - 1];
+            game.remove(removal).unwrap();
+            let removal = game.legal_removals()[0];
+            game.remove(removal).unwrap();
+
+            while game.result().is_none() {
+                let moves = game.legal_moves();
+                game.play(moves[(game.ply() * 7) % moves.len()]).unwrap();
+            }
+
+            let mut record = GameRecord::from_game(&game);
+            record.set_tag("White", "Pua \"the \\ Elder\"").unwrap();
+            record.set_tag("Event", "Club night").unwrap();
+            assert_eq!(record.tag("Position"), None);
+
+            let text = record.to_string();
+            assert!(text.lines().all(|line| line.len() <= 80));
+
+            let read = text.parse::<GameRecord>().unwrap();
+            assert_eq!(read, record);
+            assert_eq!(read.tag("White"), Some("Pua \"the \\ Elder\""));
+
+            let replayed = read.to_game().unwrap();
+            assert_eq!(replayed.board(), game.board());
+            assert_eq!(replayed.history(), game.history());
+            assert_eq!(replayed.result(), game.result());
+        }
+    }
+
+    #[test]
+    fn records_the_starting_position() {
+        let mut board = BoardBuilder::new().size(6, 6).build().unwrap();
+        let _ = board.set_piece((0, 0), Piece::EMPTY);
+        let _ = board.set_piece((0, 1), Piece::EMPTY);
+
+        let mut game = Game::new(board.clone(), Piece::WHITE).unwrap();
+        let jump = game.legal_moves()[0];
+        game.play(jump).unwrap();
+
+        let record = GameRecord::from_game(&game);
+        let position = Game::new(board, Piece::WHITE).unwrap().to_notation();
+        assert_eq!(record.tag("Position"), Some(position.as_str()));
+
+        let text = record.to_string();
+        assert!(text.contains("\n1... "));
+
+        let read = text.parse::<GameRecord>().unwrap();
+        assert_eq!(read.to_game().unwrap().board(), game.board());
+    }
+
+    #[test]
+    fn only_sets_tags_that_read_back() {
+        let mut record = GameRecord::from_game(&Game::default());
+        let invalid = |reason: &str| Err(KonaneError::InvalidNotation(reason.to_string()));
+
+        assert_eq!(
+            record.set_tag("Bad Name", "?"),
+            invalid("the tag name 'Bad Name' can only hold letters, digits and '_'")
+        );
+        assert_eq!(
+            record.set_tag("Event]", "?"),
+            invalid("the tag name 'Event]' can only hold letters, digits and '_'")
+        );
+        assert_eq!(record.set_tag("", "?"), invalid("a tag needs a name"));
+        assert_eq!(
+            record.set_tag("Event", "Club\nnight"),
+            invalid("the value of the Event tag can't hold control characters")
+        );
+        assert_eq!(
+            record.set_tag("Event", "Club\tnight"),
+            invalid("the value of the Event tag can't hold control characters")
+        );
+        assert_eq!(record.tag("Event"), None);
+
+        record.set_tag("Round_2", "\"] [Site \\").unwrap();
+        record.set_tag("Pāʻani", "Hilo, Hawaiʻi").unwrap();
+        assert_eq!(record.to_string().parse::<GameRecord>(), Ok(record));
+    }
+
+    #[test]
+    fn points_to_mistakes() {
+        assert_eq!(
+            error("[Black \"Keola\"]\n[Black \"Pua\"]"),
+            (2, 2, "the Black tag appears twice".to_string())
+        );
+        assert_eq!(
+            error("  [Black Keola]"),
+            (1, 9, "expected a space and then a quoted value".to_string())
+        );
+        assert_eq!(
+            error("[Black \"Keola]"),
+            (1, 15, "the value is missing its closing quote".to_string())
+        );
+        assert_eq!(
+            error("[Size \"5x0\"]\n\n1. a1"),
+            (1, 7, "a board can't be 5 wide and 0 high".to_string())
+        );
+        assert_eq!(
+            error("1. a1 b1\n[Black \"Keola\"]"),
+            (2, 1, "tags must come before the turns".to_string())
+        );
+        assert_eq!(
+            error("1. a1\n   b1 2. a3-a1-b1"),
+            (
+                2,
+                10,
+                "'a3-a1-b1' isn't a straight line of jumps".to_string()
+            )
+        );
+        assert_eq!(
+            error("1. a1 b1 2. a3-z"),
+            (1, 13, "'z' isn't a square".to_string())
+        );
+        assert_eq!(
+            error("1. b2"),
+            (1, 4, "the piece at b2 can't be removed".to_string())
+        );
+        assert_eq!(
+            error("[Result \"0-1\"]\n\n1. a1 b1 1-0"),
+            (
+                3,
+                10,
+                "the result 1-0 doesn't match the Result tag, 0-1".to_string()
+            )
+        );
+        assert_eq!(
+            error("1. a1 * b1"),
+            (1, 9, "nothing may follow the result".to_string())
+        );
+    }
+
+    #[test]
+    fn checks_the_result_against_the_game() {
+        let mut board = Board::create_empty();
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((0, 1), Piece::WHITE);
+        let position = Game::new(board, Piece::BLACK).unwrap().to_notation();
+
+        let text = format!("[Position \"{}\"]\n\n1. a1-c1 0-1", position);
+        assert_eq!(
+            error(&text),
+            (3, 10, "the result is 0-1 but Black won".to_string())
+        );
+
+        let text = format!("[Position \"{}\"]\n\n1. a1-c1 1-0", position);
+        assert!(text.parse::<GameRecord>().is_ok());
+
+        let text = format!("[Position \"{}\"]\n\n1. a1-c1 *", position);
+        assert_eq!(
+            error(&text),
+            (3, 10, "the result is * but Black won".to_string())
+        );
+
+        let text = format!("[Result \"0-1\"]\n[Position \"{}\"]\n\n1. a1-c1", position);
+        assert_eq!(
+            error(&text),
+            (1, 9, "the result is 0-1 but Black won".to_string())
+        );
+
+        assert_eq!(
+            error("[Result \"1-0\"]\n\n1. a1 b1"),
+            (
+                1,
+                9,
+                "the result is 1-0 but the game isn't over".to_string()
+            )
+        );
+        assert_eq!(
+            error("1. a1 b1 0-1"),
+            (
+                1,
+                10,
+                "the result is 0-1 but the game isn't over".to_string()
+            )
+        );
+
+        let mut record = GameRecord::from_game(&Game::default());
+        record.set_tag("Result", "1-0").unwrap();
+        assert_eq!(
+            record.to_game().unwrap_err(),
+            KonaneError::InvalidNotation("the result is 1-0 but the game isn't over".to_string())
+        );
+    }
+}
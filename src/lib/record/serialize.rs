@@ -0,0 +1,140 @@
+//! Serde support for game records, enabled with the `serde` feature.
+//!
+//! A record is written as an object holding its `tags`, as an object from each tag's name to
+//! its value in the order they're written, and its `turns`, each either `{ "removal": "a1" }`
+//! or `{ "jump": "a3-a1" }`:
+//!
+//! ```json
+//! {
+//!   "tags": { "Black": "Keola", "White": "?", "Size": "4x4", "Result": "*" },
+//!   "turns": [{ "removal": "a1" }, { "removal": "b1" }, { "jump": "a3-a1" }]
+//! }
+//! ```
+//!
+//! Reading a record replays its turns from the starting position its tags describe, just as
+//! [parsing](std::str::FromStr) one does.
+
+use std::fmt;
+
+use serde::{
+    de::{Error, MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::game_record::{check_tag, GameRecord};
+use crate::Turn;
+
+/// The shape a record is written in.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RecordShape {
+    tags: Tags,
+    turns: Vec<Turn>,
+}
+
+/// A record's tags, kept in the order they're written.
+struct Tags(Vec<(String, String)>);
+
+impl Serialize for Tags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (name, value) in &self.0 {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Tags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Tags, D::Error> {
+        deserializer.deserialize_map(TagsVisitor)
+    }
+}
+
+struct TagsVisitor;
+
+impl<'de> Visitor<'de> for TagsVisitor {
+    type Value = Tags;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a map from tag names to their values")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Tags, A::Error> {
+        let mut tags = Vec::<(String, String)>::new();
+        while let Some((name, value)) = map.next_entry::<String, String>()? {
+            check_tag(&name, &value).map_err(A::Error::custom)?;
+            if tags.iter().any(|(tag, _)| *tag == name) {
+                return Err(A::Error::custom(format!("the {} tag appears twice", name)));
+            }
+            tags.push((name, value));
+        }
+
+        Ok(Tags(tags))
+    }
+}
+
+impl Serialize for GameRecord {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RecordShape {
+            tags: Tags(self.tags().to_vec()),
+            turns: self.turns().to_vec(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GameRecord {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<GameRecord, D::Error> {
+        let RecordShape { tags, turns } = RecordShape::deserialize(deserializer)?;
+
+        let record = GameRecord::from_parts(tags.0, turns);
+        record.to_game().map_err(D::Error::custom)?;
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{Game, GameRecord};
+
+    #[test]
+    fn round_trips_records() {
+        let mut game = Game::default();
+        game.remove((0, 0)).unwrap();
+        game.remove((0, 1)).unwrap();
+        let jump = game.legal_moves()[0];
+        game.play(jump).unwrap();
+
+        let mut record = GameRecord::from_game(&game);
+        record.set_tag("Black", "Keola").unwrap();
+
+        let text = serde_json::to_string(&record).unwrap();
+        assert!(text.starts_with(r#"{"tags":{"Black":"Keola","White":"?","Date":"#));
+
+        let read = serde_json::from_str::<GameRecord>(&text).unwrap();
+        assert_eq!(read, record);
+        assert_eq!(read.to_game().unwrap().board(), game.board());
+    }
+
+    #[test]
+    fn rejects_bad_records() {
+        let record = |tags, turns| {
+            serde_json::from_value::<GameRecord>(json!({ "tags": tags, "turns": turns }))
+        };
+
+        assert!(record(json!({ "Size": "4x4" }), json!([{ "removal": "a1" }])).is_ok());
+        assert!(record(json!({ "Size": "4x4" }), json!([{ "removal": "b1" }])).is_err());
+        assert!(record(json!({ "Size": "4x0" }), json!([])).is_err());
+        assert!(record(json!(["Size", "4x4"]), json!([])).is_err());
+        assert!(record(json!({ "Bad Name": "?" }), json!([])).is_err());
+        assert!(record(json!({ "Event": "Club\nnight" }), json!([])).is_err());
+
+        let duplicated = r#"{"tags":{"Black":"Keola","Black":"Pua"},"turns":[]}"#;
+        let error = serde_json::from_str::<GameRecord>(duplicated).unwrap_err();
+        assert!(error.to_string().starts_with("the Black tag appears twice"));
+    }
+}
@@ -0,0 +1,372 @@
+use std::time::Instant;
+
+use super::{Bound, StopFlag, TranspositionTable};
+use crate::{Board, Evaluator, Mobility, Move, Piece};
+
+/// The score of a won position. Wins found sooner score higher, so that the search heads for
+/// the quickest win and puts off a loss for as long as possible.
+pub const WIN_SCORE: i32 = 1_000_000;
+
+/// The outcome of a search: the move to play, how good it is, and the line of play expected to
+/// follow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchResult {
+    /// The move the search recommends.
+    pub best_move: Move,
+    /// The score of the position after `best_move`, from the mover's point of view. Scores
+    /// beyond [`WIN_SCORE`] less the depth in size are forced wins or losses.
+    pub score: i32,
+    /// The expected line of play, starting with `best_move`.
+    pub principal_variation: Vec<Move>,
+    /// The number of moves searched ahead.
+    pub depth: usize,
+    /// The number of positions visited.
+    pub nodes: u64,
+}
+
+/// Search `depth` moves ahead using negamax with alpha-beta pruning, returning the best move
+/// for `side`, or `None` if `side` has no legal moves. A depth of zero is treated as one.
+///
+/// Positions at the end of the search are scored by [`Mobility`]: the number of moves available
+/// to the side to move, less those available to their opponent. Use a
+/// [`Searcher`](crate::Searcher) to score them some other way.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{best_move, Board, Direction, Move, Piece};
+///
+/// let mut board = Board::create_empty();
+/// let _ = board.set_piece((0, 0), Piece::BLACK);
+/// let _ = board.set_piece((0, 1), Piece::WHITE);
+/// let _ = board.set_piece((0, 3), Piece::WHITE);
+/// let _ = board.set_piece((1, 3), Piece::BLACK);
+///
+/// // Jumping twice leaves White without a move, whereas jumping once lets White reply
+/// let result = best_move(&board, Piece::BLACK, 3).unwrap();
+/// assert_eq!(result.best_move, Move::new((0, 0), Direction::Right, 2));
+/// assert!(result.score > 0);
+/// ```
+pub fn best_move(board: &Board, side: Piece, depth: usize) -> Option<SearchResult> {
+    Search::new(board).search(side, depth.max(1), None)
+}
+
+/// The state of a single search, which plays moves on its own copy of the board and takes them
+/// back again. A search can be given limits, in which case it gives up as soon as any of them
+/// is reached.
+pub(super) struct Search<'a> {
+    board: Board,
+    nodes: u64,
+    node_limit: Option<u64>,
+    deadline: Option<Instant>,
+    stop: Option<&'a StopFlag>,
+    table: Option<&'a mut TranspositionTable>,
+    evaluator: &'a dyn Evaluator,
+    aborted: bool,
+}
+
+impl<'a> Search<'a> {
+    pub(super) fn new(board: &Board) -> Search<'a> {
+        Search {
+            board: board.clone(),
+            nodes: 0,
+            node_limit: None,
+            deadline: None,
+            stop: None,
+            table: None,
+            evaluator: &Mobility,
+            aborted: false,
+        }
+    }
+
+    /// Give up once this many positions have been visited, counting every search made.
+    pub(super) fn with_node_limit(mut self, node_limit: Option<u64>) -> Search<'a> {
+        self.node_limit = node_limit;
+        self
+    }
+
+    /// Give up once this time has passed.
+    pub(super) fn with_deadline(mut self, deadline: Option<Instant>) -> Search<'a> {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Give up as soon as the flag is raised.
+    pub(super) fn with_stop_flag(mut self, stop: &'a StopFlag) -> Search<'a> {
+        self.stop = Some(stop);
+        self
+    }
+
+    /// Cache results in a transposition table, and use what's already there to skip searching
+    /// positions again.
+    pub(super) fn with_table(mut self, table: Option<&'a mut TranspositionTable>) -> Search<'a> {
+        self.table = table;
+        self
+    }
+
+    /// Score positions at the end of the search with `evaluator`.
+    pub(super) fn with_evaluator(mut self, evaluator: &'a dyn Evaluator) -> Search<'a> {
+        self.evaluator = evaluator;
+        self
+    }
+
+    /// The number of positions visited across every search made so far.
+    pub(super) fn nodes(&self) -> u64 {
+        self.nodes
+    }
+
+    /// Search `depth` moves ahead for `side`, trying `first` before any other move if it's
+    /// legal, or else the best move in the transposition table. Returns `None` if `side` has no
+    /// legal moves or a limit was reached.
+    pub(super) fn search(
+        &mut self,
+        side: Piece,
+        depth: usize,
+        first: Option<Move>,
+    ) -> Option<SearchResult> {
+        let key = self.board.hash_key(side);
+        let first = first.or_else(|| {
+            let table = self.table.as_deref_mut()?;
+            table.probe(key)?.best_move
+        });
+
+        let mut moves = self.board.legal_moves(side);
+        move_to_front(&mut moves, first);
+
+        let mut alpha = -WIN_SCORE - 1;
+        let mut principal_variation = vec![];
+        let mut line = vec![];
+
+        for jump in moves {
+            let score = -self.search_move(jump, side, depth, 0, -WIN_SCORE - 1, -alpha, &mut line);
+            if self.aborted {
+                return None;
+            }
+
+            if score > alpha {
+                alpha = score;
+
+                principal_variation.clear();
+                principal_variation.push(jump);
+                principal_variation.append(&mut line);
+            }
+        }
+
+        let best_move = *principal_variation.first()?;
+        if let Some(table) = self.table.as_deref_mut() {
+            table.store(key, depth, Bound::Exact, alpha, Some(best_move), 0);
+        }
+
+        Some(SearchResult {
+            best_move,
+            score: alpha,
+            principal_variation,
+            depth,
+            nodes: self.nodes,
+        })
+    }
+
+    /// Play a move, score the resulting position from the opponent's point of view, and take
+    /// the move back again.
+    #[allow(clippy::too_many_arguments)]
+    fn search_move(
+        &mut self,
+        jump: Move,
+        side: Piece,
+        depth: usize,
+        ply: usize,
+        alpha: i32,
+        beta: i32,
+        line: &mut Vec<Move>,
+    ) -> i32 {
+        self.board
+            .apply_move(jump)
+            .expect("generated moves are always legal");
+        let score = self.negamax(side.opponent(), depth - 1, ply + 1, alpha, beta, line);
+        self.board.unmake_move().expect("the move was just applied");
+
+        score
+    }
+
+    /// Score the position for `side` to move, searching `depth` more moves ahead, and fill in
+    /// the best line of play found.
+    fn negamax(
+        &mut self,
+        side: Piece,
+        depth: usize,
+        ply: usize,
+        mut alpha: i32,
+        beta: i32,
+        principal_variation: &mut Vec<Move>,
+    ) -> i32 {
+        self.nodes += 1;
+        principal_variation.clear();
+
+        if self.limit_reached() {
+            self.aborted = true;
+            return 0;
+        }
+
+        let mut moves = self.board.legal_moves(side);
+        if moves.is_empty() {
+            return -(WIN_SCORE - ply as i32);
+        }
+        if depth == 0 {
+            return self.evaluator.evaluate(&self.board, side);
+        }
+
+        let key = self.board.hash_key(side);
+        let mut beta = beta;
+
+        if let Some(entry) = self.table.as_deref_mut().and_then(|table| table.probe(key)) {
+            if entry.depth >= depth {
+                let score = entry.score_at(ply);
+                match entry.bound {
+                    Bound::Exact => {
+                        principal_variation.extend(entry.best_move);
+                        return score;
+                    }
+                    Bound::Lower => alpha = alpha.max(score),
+                    Bound::Upper => beta = beta.min(score),
+                }
+
+                if alpha >= beta {
+                    principal_variation.extend(entry.best_move);
+                    return score;
+                }
+            }
+
+            move_to_front(&mut moves, entry.best_move);
+        }
+
+        let window_alpha = alpha;
+        let mut best_score = -WIN_SCORE - 1;
+        let mut line = vec![];
+
+        for jump in moves {
+            let score = -self.search_move(jump, side, depth, ply, -beta, -alpha, &mut line);
+            if self.aborted {
+                return 0;
+            }
+
+            if score > best_score {
+                best_score = score;
+
+                principal_variation.clear();
+                principal_variation.push(jump);
+                principal_variation.append(&mut line);
+            }
+
+            alpha = alpha.max(score);
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        if let Some(table) = self.table.as_deref_mut() {
+            let bound = if best_score <= window_alpha {
+                Bound::Upper
+            } else if best_score >= beta {
+                Bound::Lower
+            } else {
+                Bound::Exact
+            };
+            let best_move = principal_variation.first().copied();
+
+            table.store(key, depth, bound, best_score, best_move, ply);
+        }
+
+        best_score
+    }
+
+    /// Whether any of the search's limits has been reached. The clock is only checked every so
+    /// often, as it's much slower to read than the node count or stop flag.
+    fn limit_reached(&self) -> bool {
+        if self.node_limit.is_some_and(|limit| self.nodes > limit)
+            || self.stop.is_some_and(StopFlag::is_stopped)
+        {
+            return true;
+        }
+
+        self.nodes.is_multiple_of(1024)
+            && self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
+
+/// Move `first` to the front of the list of moves, if it's there, leaving the rest in order.
+fn move_to_front(moves: &mut [Move], first: Option<Move>) {
+    if let Some(position) = moves.iter().position(|&jump| Some(jump) == first) {
+        moves[..=position].rotate_right(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WIN_SCORE;
+    use crate::testing::opened;
+    use crate::{best_move, Board, Direction, Game, Move, Piece};
+
+    /// Plain negamax without pruning, to check that pruning never changes the score.
+    fn minimax(board: &mut Board, side: Piece, depth: usize, ply: usize) -> i32 {
+        let moves = board.legal_moves(side);
+        if moves.is_empty() {
+            return -(WIN_SCORE - ply as i32);
+        }
+        if depth == 0 {
+            return moves.len() as i32 - board.legal_moves(side.opponent()).len() as i32;
+        }
+
+        moves
+            .into_iter()
+            .map(|jump| {
+                board.apply_move(jump).unwrap();
+                let score = -minimax(board, side.opponent(), depth - 1, ply + 1);
+                board.unmake_move().unwrap();
+                score
+            })
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn pruning_matches_minimax() {
+        let mut board = opened().board().clone();
+
+        for depth in 1..=4 {
+            let result = best_move(&board, Piece::BLACK, depth).unwrap();
+            assert_eq!(result.score, minimax(&mut board, Piece::BLACK, depth, 0));
+        }
+    }
+
+    #[test]
+    fn principal_variation_is_playable() {
+        let board = opened().board().clone();
+        let result = best_move(&board, Piece::BLACK, 4).unwrap();
+
+        assert_eq!(result.principal_variation.first(), Some(&result.best_move));
+
+        let mut game = Game::new(board, Piece::BLACK).unwrap();
+        for &jump in &result.principal_variation {
+            game.play(jump).unwrap();
+        }
+    }
+
+    #[test]
+    fn scores_a_win_by_its_distance() {
+        let mut board = Board::create_empty();
+        let _ = board.set_piece((5, 5), Piece::WHITE);
+        let _ = board.set_piece((4, 5), Piece::BLACK);
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+
+        let result = best_move(&board, Piece::WHITE, 5).unwrap();
+        assert_eq!(result.best_move, Move::new((5, 5), Direction::Up, 1));
+        assert_eq!(result.score, WIN_SCORE - 1);
+    }
+
+    #[test]
+    fn no_move_when_stuck() {
+        assert_eq!(best_move(&Board::default(), Piece::BLACK, 3), None);
+    }
+}
@@ -0,0 +1,264 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use super::{alphabeta::Search, SearchResult, TranspositionTable};
+use crate::{Board, Evaluator, Mobility, Piece};
+
+/// A flag that tells a running search to stop as soon as it can. Clones share the same flag,
+/// so one can be handed to a search while another is kept to stop it from a different thread.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::StopFlag;
+///
+/// let stop = StopFlag::new();
+/// let handle = stop.clone();
+///
+/// std::thread::spawn(move || handle.stop()).join().unwrap();
+/// assert!(stop.is_stopped());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct StopFlag(Arc<AtomicBool>);
+
+impl StopFlag {
+    /// Create a flag that hasn't been raised.
+    pub fn new() -> StopFlag {
+        StopFlag::default()
+    }
+
+    /// Ask every search watching this flag to stop.
+    pub fn stop(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether the flag has been raised.
+    pub fn is_stopped(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// How far, how long, and how many positions an iterative deepening search may go before it
+/// stops. There are no limits by default, in which case the search runs until every line of
+/// play has been followed to the end of the game.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use konane_engine::SearchLimits;
+///
+/// let limits = SearchLimits::new()
+///     .depth(12)
+///     .time(Duration::from_millis(500))
+///     .nodes(1_000_000);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SearchLimits {
+    depth: Option<usize>,
+    time: Option<Duration>,
+    nodes: Option<u64>,
+}
+
+impl SearchLimits {
+    /// Limits that let the search run to completion.
+    pub fn new() -> SearchLimits {
+        SearchLimits::default()
+    }
+
+    /// Search no more than `depth` moves ahead.
+    pub fn depth(mut self, depth: usize) -> SearchLimits {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Stop searching once `time` has passed.
+    pub fn time(mut self, time: Duration) -> SearchLimits {
+        self.time = Some(time);
+        self
+    }
+
+    /// Stop searching once `nodes` positions have been visited.
+    pub fn nodes(mut self, nodes: u64) -> SearchLimits {
+        self.nodes = Some(nodes);
+        self
+    }
+}
+
+/// Search one move ahead, then two, and so on until a limit is reached or the stop flag is
+/// raised, returning the result of the deepest search that finished. Each search tries the
+/// previous search's best move first. Returns `None` if `side` has no legal moves.
+///
+/// If not even the first search finishes, the first legal move is returned with a score of
+/// zero and a depth of zero.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use konane_engine::{iterative_deepening, Game, SearchLimits, StopFlag};
+///
+/// let mut game = Game::default();
+/// game.remove((0, 0)).unwrap();
+/// game.remove((0, 1)).unwrap();
+///
+/// let limits = SearchLimits::new().time(Duration::from_millis(50));
+/// let result = iterative_deepening(game.board(), game.side_to_move(), &limits, &StopFlag::new());
+///
+/// assert!(game.legal_moves().contains(&result.unwrap().best_move));
+/// ```
+pub fn iterative_deepening(
+    board: &Board,
+    side: Piece,
+    limits: &SearchLimits,
+    stop: &StopFlag,
+) -> Option<SearchResult> {
+    deepen(board, side, limits, stop, None, &Mobility)
+}
+
+/// Iterative deepening, optionally sharing a transposition table between each search, scoring
+/// positions at the end of each search with `evaluator`.
+pub(super) fn deepen(
+    board: &Board,
+    side: Piece,
+    limits: &SearchLimits,
+    stop: &StopFlag,
+    table: Option<&mut TranspositionTable>,
+    evaluator: &dyn Evaluator,
+) -> Option<SearchResult> {
+    let first_move = *board.legal_moves(side).first()?;
+
+    let deadline = limits.time.map(|time| Instant::now() + time);
+    let mut search = Search::new(board)
+        .with_node_limit(limits.nodes)
+        .with_deadline(deadline)
+        .with_stop_flag(stop)
+        .with_table(table)
+        .with_evaluator(evaluator);
+
+    // Every move captures at least one piece, so no game can last longer than this.
+    let longest_game = board.count(Piece::BLACK) + board.count(Piece::WHITE);
+    let max_depth = limits.depth.unwrap_or(longest_game).max(1);
+
+    let mut completed: Option<SearchResult> = None;
+
+    for depth in 1..=max_depth {
+        let first = completed.as_ref().map(|result| result.best_move);
+        let Some(result) = search.search(side, depth, first) else {
+            break;
+        };
+
+        let proven = result.score.abs() >= super::WIN_SCORE - depth as i32;
+        completed = Some(result);
+
+        if proven || depth >= longest_game {
+            break;
+        }
+    }
+
+    Some(completed.unwrap_or(SearchResult {
+        best_move: first_move,
+        score: 0,
+        principal_variation: vec![first_move],
+        depth: 0,
+        nodes: search.nodes(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::opened;
+    use std::time::{Duration, Instant};
+
+    use crate::{
+        best_move, iterative_deepening, Board, BoardBuilder, Game, Piece, SearchLimits, StopFlag,
+    };
+
+    #[test]
+    fn deepening_to_a_depth_matches_fixed_depth() {
+        let board = opened().board().clone();
+
+        let deepened = iterative_deepening(
+            &board,
+            Piece::BLACK,
+            &SearchLimits::new().depth(4),
+            &StopFlag::new(),
+        )
+        .unwrap();
+        let fixed = best_move(&board, Piece::BLACK, 4).unwrap();
+
+        assert_eq!(deepened.depth, 4);
+        assert_eq!(deepened.score, fixed.score);
+    }
+
+    #[test]
+    fn stops_at_the_node_limit() {
+        let board = BoardBuilder::new().size(8, 8).build().unwrap();
+        let mut game = Game::opening(board);
+        game.remove((3, 3)).unwrap();
+        game.remove((3, 4)).unwrap();
+
+        // Searching this deep visits more positions than the limit allows
+        let unlimited = SearchLimits::new().depth(7);
+        let full =
+            iterative_deepening(game.board(), Piece::BLACK, &unlimited, &StopFlag::new()).unwrap();
+        assert_eq!(full.depth, 7);
+        assert!(full.nodes > 5_000);
+
+        let limits = SearchLimits::new().depth(7).nodes(5_000);
+        let result =
+            iterative_deepening(game.board(), Piece::BLACK, &limits, &StopFlag::new()).unwrap();
+
+        assert!(result.depth >= 1);
+        assert!(result.depth < full.depth);
+        assert!(result.nodes <= 5_001);
+    }
+
+    #[test]
+    fn honours_the_time_budget() {
+        let board = BoardBuilder::new().size(10, 10).build().unwrap();
+        let mut game = Game::opening(board);
+        game.remove((4, 4)).unwrap();
+        game.remove((4, 5)).unwrap();
+
+        let start = Instant::now();
+        let limits = SearchLimits::new().time(Duration::from_millis(100));
+        iterative_deepening(game.board(), Piece::BLACK, &limits, &StopFlag::new()).unwrap();
+
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn raised_flag_falls_back_to_a_legal_move() {
+        let board = opened().board().clone();
+        let stop = StopFlag::new();
+        stop.stop();
+
+        let result =
+            iterative_deepening(&board, Piece::BLACK, &SearchLimits::new(), &stop).unwrap();
+
+        assert_eq!(result.depth, 0);
+        assert!(board.legal_moves(Piece::BLACK).contains(&result.best_move));
+    }
+
+    #[test]
+    fn stops_once_the_game_is_solved() {
+        let mut board = Board::create_empty();
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((0, 1), Piece::WHITE);
+
+        let result =
+            iterative_deepening(&board, Piece::BLACK, &SearchLimits::new(), &StopFlag::new())
+                .unwrap();
+
+        assert_eq!(result.depth, 1);
+        assert_eq!(result.score, crate::WIN_SCORE - 1);
+    }
+}
@@ -0,0 +1,11 @@
+mod alphabeta;
+mod iterative;
+mod searcher;
+mod solver;
+mod tt;
+
+pub use alphabeta::{best_move, SearchResult, WIN_SCORE};
+pub use iterative::{iterative_deepening, SearchLimits, StopFlag};
+pub use searcher::Searcher;
+pub use solver::{Outcome, Solution, Solver};
+pub use tt::{Bound, TableEntry, TableStats, TranspositionTable};
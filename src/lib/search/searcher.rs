@@ -0,0 +1,177 @@
+use super::{
+    alphabeta::Search, iterative::deepen, SearchLimits, SearchResult, StopFlag, TranspositionTable,
+};
+use crate::{Board, Evaluator, Mobility, Piece};
+
+/// A search that keeps what it learns from one move to the next in a transposition table, and
+/// scores positions at the end of the search with an [`Evaluator`] of your choosing. Without a
+/// table, and with the default [`Mobility`] evaluator, it searches exactly like
+/// [`best_move`](crate::best_move) and [`iterative_deepening`](crate::iterative_deepening).
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{Game, SafeMoves, Searcher, SearchLimits, StopFlag, TranspositionTable};
+///
+/// let mut game = Game::default();
+/// game.remove((2, 2)).unwrap();
+/// game.remove((2, 3)).unwrap();
+///
+/// let mut searcher = Searcher::new()
+///     .with_table(TranspositionTable::with_megabytes(1))
+///     .with_evaluator(SafeMoves);
+/// let limits = SearchLimits::new().depth(5);
+/// let result = searcher
+///     .iterative_deepening(game.board(), game.side_to_move(), &limits, &StopFlag::new())
+///     .unwrap();
+///
+/// assert!(game.legal_moves().contains(&result.best_move));
+/// assert!(searcher.table().unwrap().stats().hits > 0);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Searcher<E = Mobility> {
+    table: Option<TranspositionTable>,
+    evaluator: E,
+}
+
+impl Searcher {
+    /// Create a searcher without a transposition table, which scores positions by mobility.
+    pub fn new() -> Searcher {
+        Searcher::default()
+    }
+}
+
+impl<E: Evaluator> Searcher<E> {
+    /// Use `table` to cache search results.
+    pub fn with_table(mut self, table: TranspositionTable) -> Searcher<E> {
+        self.table = Some(table);
+        self
+    }
+
+    /// Score positions at the end of the search with `evaluator`.
+    pub fn with_evaluator<F: Evaluator>(self, evaluator: F) -> Searcher<F> {
+        Searcher {
+            table: self.table,
+            evaluator,
+        }
+    }
+
+    /// The searcher's evaluator.
+    pub fn evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    /// The searcher's transposition table, if it has one.
+    pub fn table(&self) -> Option<&TranspositionTable> {
+        self.table.as_ref()
+    }
+
+    /// Search `depth` moves ahead for `side`, like [`best_move`](crate::best_move).
+    pub fn best_move(&mut self, board: &Board, side: Piece, depth: usize) -> Option<SearchResult> {
+        self.new_search();
+
+        Search::new(board)
+            .with_table(self.table.as_mut())
+            .with_evaluator(&self.evaluator)
+            .search(side, depth.max(1), None)
+    }
+
+    /// Search deeper and deeper for `side` until a limit is reached, like
+    /// [`iterative_deepening`](crate::iterative_deepening).
+    pub fn iterative_deepening(
+        &mut self,
+        board: &Board,
+        side: Piece,
+        limits: &SearchLimits,
+        stop: &StopFlag,
+    ) -> Option<SearchResult> {
+        self.new_search();
+
+        deepen(
+            board,
+            side,
+            limits,
+            stop,
+            self.table.as_mut(),
+            &self.evaluator,
+        )
+    }
+
+    fn new_search(&mut self) {
+        if let Some(table) = self.table.as_mut() {
+            table.new_search();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::opened;
+    use crate::{
+        best_move, BoardBuilder, Game, Mobility, Piece, SearchLimits, Searcher, StopFlag,
+        TranspositionTable, Weighted, WIN_SCORE,
+    };
+
+    fn with_table() -> Searcher {
+        Searcher::new().with_table(TranspositionTable::with_megabytes(1))
+    }
+
+    #[test]
+    fn table_finds_the_same_result_for_a_solved_game() {
+        let mut game = Game::opening(BoardBuilder::new().size(5, 4).build().unwrap());
+        game.remove((0, 0)).unwrap();
+        game.remove((0, 1)).unwrap();
+        let board = game.board().clone();
+        let limits = SearchLimits::new();
+
+        let plain = Searcher::new()
+            .iterative_deepening(&board, Piece::BLACK, &limits, &StopFlag::new())
+            .unwrap();
+        let cached = with_table()
+            .iterative_deepening(&board, Piece::BLACK, &limits, &StopFlag::new())
+            .unwrap();
+
+        assert!(plain.score.abs() > WIN_SCORE - 100);
+        assert_eq!(cached.score, plain.score);
+        assert!(cached.nodes < plain.nodes);
+    }
+
+    #[test]
+    fn searching_again_reuses_the_table() {
+        let board = opened().board().clone();
+        let mut searcher = with_table();
+
+        let first = searcher.best_move(&board, Piece::BLACK, 5).unwrap();
+        let second = searcher.best_move(&board, Piece::BLACK, 5).unwrap();
+
+        assert_eq!(second.score, first.score);
+        assert!(second.nodes < first.nodes);
+
+        let stats = searcher.table().unwrap().stats();
+        assert!(stats.hits > 0);
+        assert!(stats.hit_rate() > 0.0 && stats.hit_rate() <= 1.0);
+    }
+
+    #[test]
+    fn without_a_table_matches_best_move() {
+        let board = opened().board().clone();
+        let result = Searcher::new().best_move(&board, Piece::BLACK, 4);
+
+        assert_eq!(result, best_move(&board, Piece::BLACK, 4));
+        assert!(Searcher::new().table().is_none());
+    }
+
+    #[test]
+    fn evaluator_changes_the_score() {
+        let board = opened().board().clone();
+
+        let mobility = Searcher::new().best_move(&board, Piece::BLACK, 3).unwrap();
+        let doubled = Searcher::new()
+            .with_evaluator(Weighted::new().with(2, Mobility))
+            .best_move(&board, Piece::BLACK, 3)
+            .unwrap();
+
+        assert_eq!(doubled.score, 2 * mobility.score);
+        assert_eq!(doubled.best_move, mobility.best_move);
+    }
+}
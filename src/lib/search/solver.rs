@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+
+use crate::{Board, Move, Piece, ValueTable};
+
+/// Whether the side to move wins or loses with best play.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Win,
+    Loss,
+}
+
+/// A proven result for a position.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Solution {
+    /// Whether the side to move wins or loses, however their opponent plays.
+    pub outcome: Outcome,
+    /// A move that keeps the win, or `None` if the position is lost, in which case every move
+    /// was searched and shown to lose.
+    pub winning_move: Option<Move>,
+    /// The number of positions visited.
+    pub nodes: u64,
+}
+
+/// Solves positions exactly by searching every line of play to the end of the game. The last
+/// player able to move wins, so every position is either won or lost for the side to move.
+///
+/// Solved positions are remembered, both to skip transpositions within a search and to answer
+/// later searches from the same game more quickly. The time taken grows exponentially with the
+/// number of moves left, so this is only practical for endgames and small boards.
+///
+/// Once a position falls apart into [regions](Board::regions), who wins depends on more than
+/// who wins each region, so the regions' [combinatorial values](crate::GameValue) are added up
+/// instead. Each region has far fewer lines of play than the whole board.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{Board, Direction, Move, Outcome, Piece, Solver};
+///
+/// let mut board = Board::create_empty();
+/// let _ = board.set_piece((0, 0), Piece::BLACK);
+/// let _ = board.set_piece((0, 1), Piece::WHITE);
+/// let _ = board.set_piece((0, 3), Piece::WHITE);
+/// let _ = board.set_piece((1, 3), Piece::BLACK);
+///
+/// let mut solver = Solver::new();
+///
+/// // Jumping twice leaves White without a move
+/// let black = solver.solve(&board, Piece::BLACK);
+/// assert_eq!(black.outcome, Outcome::Win);
+/// assert_eq!(black.winning_move, Some(Move::new((0, 0), Direction::Right, 2)));
+///
+/// // White has only one jump, after which Black gets the last move
+/// let white = solver.solve(&board, Piece::WHITE);
+/// assert_eq!(white.outcome, Outcome::Loss);
+/// assert_eq!(white.winning_move, None);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Solver {
+    /// Whether the side to move wins each position, by hash key. The board is kept alongside,
+    /// so that two positions sharing a key can't be mistaken for each other. The key also
+    /// covers the side to move, so equal boards under the same key have the same side to move.
+    solved: HashMap<u64, (Board, bool)>,
+    /// The values of regions of positions that have split up.
+    values: ValueTable,
+    nodes: u64,
+}
+
+impl Solver {
+    /// Create a solver that hasn't solved anything yet.
+    pub fn new() -> Solver {
+        Solver::default()
+    }
+
+    /// Solve `board` with `side` to move.
+    pub fn solve(&mut self, board: &Board, side: Piece) -> Solution {
+        let mut board = board.clone();
+        self.nodes = 0;
+
+        let winning_move = board.legal_moves(side).into_iter().find(|&jump| {
+            board
+                .apply_move(jump)
+                .expect("generated moves are always legal");
+            let wins = !self.wins(&mut board, side.opponent());
+            board.unmake_move().expect("the move was just applied");
+
+            wins
+        });
+
+        Solution {
+            outcome: match winning_move {
+                Some(_) => Outcome::Win,
+                None => Outcome::Loss,
+            },
+            winning_move,
+            nodes: self.nodes,
+        }
+    }
+
+    /// The number of positions the solver remembers.
+    pub fn positions(&self) -> usize {
+        self.solved.len()
+    }
+
+    /// Forget every solved position.
+    pub fn clear(&mut self) {
+        self.solved.clear();
+        self.values = ValueTable::new();
+    }
+
+    /// Whether `side` wins `board` with best play.
+    fn wins(&mut self, board: &mut Board, side: Piece) -> bool {
+        self.nodes += 1;
+
+        let key = board.hash_key(side);
+        if let Some((_, wins)) = self.solved.get(&key).filter(|(solved, _)| solved == board) {
+            return *wins;
+        }
+
+        // Regions where nobody can move make no difference to the result
+        let regions = board
+            .regions()
+            .into_iter()
+            .filter(|region| {
+                !region.legal_moves(Piece::BLACK).is_empty()
+                    || !region.legal_moves(Piece::WHITE).is_empty()
+            })
+            .collect::<Vec<_>>();
+        if regions.len() > 1 {
+            let value = regions
+                .iter()
+                .map(|region| self.values.value(region))
+                .reduce(|sum, value| sum + value)
+                .expect("there's more than one region");
+
+            let wins = value.winner(side) == side;
+            self.solved.insert(key, (board.position(), wins));
+            return wins;
+        }
+
+        let wins = board.legal_moves(side).into_iter().any(|jump| {
+            board
+                .apply_move(jump)
+                .expect("generated moves are always legal");
+            let wins = !self.wins(board, side.opponent());
+            board.unmake_move().expect("the move was just applied");
+
+            wins
+        });
+
+        self.solved.insert(key, (board.position(), wins));
+        wins
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Outcome;
+    use crate::{
+        iterative_deepening, Board, BoardBuilder, Game, Piece, SearchLimits, Solver, StopFlag,
+    };
+
+    fn small_game() -> Game {
+        let mut game = Game::opening(BoardBuilder::new().size(5, 4).build().unwrap());
+        game.remove((0, 0)).unwrap();
+        game.remove((0, 1)).unwrap();
+
+        game
+    }
+
+    #[test]
+    fn stuck_side_loses() {
+        let solution = Solver::new().solve(&Board::default(), Piece::BLACK);
+
+        assert_eq!(solution.outcome, Outcome::Loss);
+        assert_eq!(solution.winning_move, None);
+    }
+
+    #[test]
+    fn solves_separate_regions() {
+        // Whoever moves first, Black's spare jump in the corner outlasts the jump each player
+        // has at the bottom
+        let mut board = Board::create_empty();
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((0, 1), Piece::WHITE);
+        let _ = board.set_piece((5, 3), Piece::BLACK);
+        let _ = board.set_piece((5, 4), Piece::WHITE);
+
+        let mut solver = Solver::new();
+        assert_eq!(solver.solve(&board, Piece::BLACK).outcome, Outcome::Win);
+        assert_eq!(solver.solve(&board, Piece::WHITE).outcome, Outcome::Loss);
+    }
+
+    #[test]
+    fn ignores_positions_that_only_share_a_key() {
+        let mut board = Board::create_empty();
+        let _ = board.set_piece((0, 0), Piece::BLACK);
+        let _ = board.set_piece((0, 1), Piece::WHITE);
+        let _ = board.set_piece((0, 3), Piece::WHITE);
+        let _ = board.set_piece((1, 3), Piece::BLACK);
+
+        // Pretend some other position shares its key with every reply and is a win for White
+        let mut solver = Solver::new();
+        for jump in board.legal_moves(Piece::BLACK) {
+            let mut reply = board.clone();
+            reply.apply_move(jump).unwrap();
+            let key = reply.hash_key(Piece::WHITE);
+            solver.solved.insert(key, (Board::default(), true));
+        }
+
+        assert_eq!(solver.solve(&board, Piece::BLACK).outcome, Outcome::Win);
+    }
+
+    #[test]
+    fn agrees_with_a_full_depth_search() {
+        let game = small_game();
+        let solution = Solver::new().solve(game.board(), Piece::BLACK);
+
+        let searched = iterative_deepening(
+            game.board(),
+            Piece::BLACK,
+            &SearchLimits::new(),
+            &StopFlag::new(),
+        )
+        .unwrap();
+
+        assert_eq!(solution.outcome == Outcome::Win, searched.score > 0);
+    }
+
+    #[test]
+    fn winning_moves_keep_winning() {
+        let mut game = small_game();
+        let mut solver = Solver::new();
+
+        // Whoever is winning keeps to their winning moves, so they must make the last move
+        let winner = match solver.solve(game.board(), Piece::BLACK).outcome {
+            Outcome::Win => Piece::BLACK,
+            Outcome::Loss => Piece::WHITE,
+        };
+
+        while game.result().is_none() {
+            let side = game.side_to_move();
+            let solution = solver.solve(game.board(), side);
+            assert_eq!(solution.outcome == Outcome::Win, side == winner);
+
+            let jump = solution
+                .winning_move
+                .unwrap_or_else(|| game.legal_moves()[0]);
+            game.play(jump).unwrap();
+        }
+
+        assert_eq!(game.result(), Some(winner));
+        assert!(solver.positions() > 0);
+    }
+}
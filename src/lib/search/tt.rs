@@ -0,0 +1,295 @@
+use std::mem;
+
+use super::WIN_SCORE;
+use crate::Move;
+
+/// How a stored score relates to the position's true score.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Bound {
+    /// The score is exact.
+    Exact,
+    /// The search failed high, so the true score is at least this.
+    Lower,
+    /// The search failed low, so the true score is at most this.
+    Upper,
+}
+
+/// What the search learnt about a position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TableEntry {
+    /// The position's hash key.
+    pub key: u64,
+    /// How many moves ahead the position was searched.
+    pub depth: usize,
+    /// How `score` relates to the true score.
+    pub bound: Bound,
+    /// The position's score for the side to move. Wins and losses are stored as a distance
+    /// from this position rather than from the root, so that they stay correct wherever the
+    /// position turns up in the tree.
+    pub score: i32,
+    /// The best move found, if any move raised the score above the search window.
+    pub best_move: Option<Move>,
+    /// The search the entry was stored in, so that entries from old searches can be replaced.
+    generation: u8,
+}
+
+impl TableEntry {
+    /// The stored score, adjusted for a position `ply` moves from the root.
+    pub(super) fn score_at(&self, ply: usize) -> i32 {
+        from_table_score(self.score, ply)
+    }
+}
+
+/// Counters for tuning the table's size and replacement scheme.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TableStats {
+    /// The number of lookups made.
+    pub probes: u64,
+    /// The number of lookups that found their position.
+    pub hits: u64,
+    /// The number of entries stored.
+    pub stores: u64,
+    /// The number of stores that replaced an entry for a different position.
+    pub overwrites: u64,
+}
+
+impl TableStats {
+    /// The fraction of lookups that found their position, or zero before any lookups.
+    pub fn hit_rate(&self) -> f64 {
+        if self.probes == 0 {
+            0.0
+        } else {
+            self.hits as f64 / self.probes as f64
+        }
+    }
+}
+
+/// Two entries sharing an index: one kept for the deepest search, and one for whatever was
+/// stored most recently.
+#[derive(Clone, Copy, Debug, Default)]
+struct Bucket {
+    deep: Option<TableEntry>,
+    recent: Option<TableEntry>,
+}
+
+/// A fixed-size cache of search results, indexed by position hash key.
+///
+/// Each index holds a bucket of two entries. A new position takes the place of the deeper of
+/// the two if it was searched at least as deep, or if that entry is left over from an earlier
+/// search, pushing the old entry into the other place. Otherwise it takes the other place.
+///
+/// # Example
+///
+/// ```rust
+/// use konane_engine::{Bound, TranspositionTable};
+///
+/// let mut table = TranspositionTable::with_memory(1 << 20);
+///
+/// table.store(0xdead_beef, 4, Bound::Exact, 12, None, 0);
+///
+/// let entry = table.probe(0xdead_beef).unwrap();
+/// assert_eq!(entry.depth, 4);
+/// assert_eq!(entry.score, 12);
+///
+/// assert_eq!(table.probe(0xcafe), None);
+/// assert_eq!(table.stats().hit_rate(), 0.5);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TranspositionTable {
+    buckets: Vec<Bucket>,
+    generation: u8,
+    stats: TableStats,
+}
+
+impl TranspositionTable {
+    /// Create a table that uses at most `bytes` of memory. The number of buckets is rounded
+    /// down to a power of two, with at least one bucket however little memory is given.
+    pub fn with_memory(bytes: usize) -> TranspositionTable {
+        let capacity = (bytes / mem::size_of::<Bucket>()).max(1);
+        let buckets = 1 << capacity.ilog2();
+
+        TranspositionTable {
+            buckets: vec![Bucket::default(); buckets],
+            generation: 0,
+            stats: TableStats::default(),
+        }
+    }
+
+    /// Create a table that uses at most `megabytes` of memory.
+    pub fn with_megabytes(megabytes: usize) -> TranspositionTable {
+        TranspositionTable::with_memory(megabytes << 20)
+    }
+
+    /// The number of entries the table can hold.
+    pub fn capacity(&self) -> usize {
+        self.buckets.len() * 2
+    }
+
+    /// Look up a position by its hash key.
+    pub fn probe(&mut self, key: u64) -> Option<TableEntry> {
+        self.stats.probes += 1;
+
+        let bucket = &self.buckets[self.index(key)];
+        let entry = [bucket.deep, bucket.recent]
+            .into_iter()
+            .flatten()
+            .find(|entry| entry.key == key)?;
+
+        self.stats.hits += 1;
+        Some(entry)
+    }
+
+    /// Remember what a search found about a position `ply` moves from the root.
+    pub fn store(
+        &mut self,
+        key: u64,
+        depth: usize,
+        bound: Bound,
+        score: i32,
+        best_move: Option<Move>,
+        ply: usize,
+    ) {
+        self.stats.stores += 1;
+
+        let generation = self.generation;
+        let index = self.index(key);
+        let bucket = &mut self.buckets[index];
+
+        let mut entry = TableEntry {
+            key,
+            depth,
+            bound,
+            score: to_table_score(score, ply),
+            best_move,
+            generation,
+        };
+
+        // A position already in the bucket is updated where it is, keeping its best move if
+        // this search didn't find one
+        let existing = [&mut bucket.deep, &mut bucket.recent]
+            .into_iter()
+            .flatten()
+            .find(|old| old.key == key);
+        if let Some(old) = existing {
+            entry.best_move = entry.best_move.or(old.best_move);
+            *old = entry;
+            return;
+        }
+
+        let replace_deep = match bucket.deep {
+            None => true,
+            Some(deep) => depth >= deep.depth || deep.generation != generation,
+        };
+
+        let dropped = if replace_deep {
+            let demoted = bucket.deep.replace(entry);
+            mem::replace(&mut bucket.recent, demoted)
+        } else {
+            bucket.recent.replace(entry)
+        };
+
+        if dropped.is_some() {
+            self.stats.overwrites += 1;
+        }
+    }
+
+    /// Start a new search. Entries from earlier searches are kept, but are replaced in
+    /// preference to entries from this one.
+    pub fn new_search(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Forget every entry and reset the statistics.
+    pub fn clear(&mut self) {
+        self.buckets.fill(Bucket::default());
+        self.generation = 0;
+        self.stats = TableStats::default();
+    }
+
+    /// How the table has been used since it was created or last cleared.
+    pub fn stats(&self) -> TableStats {
+        self.stats
+    }
+
+    fn index(&self, key: u64) -> usize {
+        key as usize & (self.buckets.len() - 1)
+    }
+}
+
+/// Scores beyond this are wins or losses, whose distance from the root changes from one place
+/// in the tree to another.
+const WIN_THRESHOLD: i32 = WIN_SCORE - 10_000;
+
+fn to_table_score(score: i32, ply: usize) -> i32 {
+    match score {
+        score if score > WIN_THRESHOLD => score + ply as i32,
+        score if score < -WIN_THRESHOLD => score - ply as i32,
+        score => score,
+    }
+}
+
+fn from_table_score(score: i32, ply: usize) -> i32 {
+    match score {
+        score if score > WIN_THRESHOLD => score - ply as i32,
+        score if score < -WIN_THRESHOLD => score + ply as i32,
+        score => score,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bound, TranspositionTable};
+    use crate::{Direction, Move, WIN_SCORE};
+
+    #[test]
+    fn win_scores_are_relative_to_the_position() {
+        let mut table = TranspositionTable::with_memory(4096);
+
+        // A win two moves after a position found three moves from the root
+        table.store(7, 2, Bound::Exact, WIN_SCORE - 5, None, 3);
+        let entry = table.probe(7).unwrap();
+
+        assert_eq!(entry.score, WIN_SCORE - 2);
+        assert_eq!(entry.score_at(1), WIN_SCORE - 3);
+    }
+
+    #[test]
+    fn deeper_entries_survive_shallower_stores() {
+        let mut table = TranspositionTable::with_memory(1);
+        assert_eq!(table.capacity(), 2);
+
+        table.store(1, 8, Bound::Exact, 10, None, 0);
+        table.store(2, 2, Bound::Lower, 20, None, 0);
+        table.store(3, 3, Bound::Upper, 30, None, 0);
+
+        assert_eq!(table.probe(1).map(|entry| entry.depth), Some(8));
+        assert_eq!(table.probe(2), None);
+        assert_eq!(table.probe(3).map(|entry| entry.score), Some(30));
+        assert_eq!(table.stats().overwrites, 1);
+
+        // Entries from old searches give way to new ones
+        table.new_search();
+        table.store(4, 1, Bound::Exact, 40, None, 0);
+        assert_eq!(table.probe(4).map(|entry| entry.depth), Some(1));
+        assert_eq!(table.probe(1).map(|entry| entry.depth), Some(8));
+        assert_eq!(table.probe(3), None);
+        assert_eq!(table.stats().overwrites, 2);
+    }
+
+    #[test]
+    fn restoring_a_position_keeps_its_best_move() {
+        let mut table = TranspositionTable::with_memory(1 << 10);
+        let jump = Move::new((0, 0), Direction::Right, 1);
+
+        table.store(9, 1, Bound::Exact, 5, Some(jump), 0);
+        table.store(9, 2, Bound::Upper, -5, None, 0);
+
+        let entry = table.probe(9).unwrap();
+        assert_eq!(entry.depth, 2);
+        assert_eq!(entry.best_move, Some(jump));
+
+        table.clear();
+        assert_eq!(table.probe(9), None);
+        assert_eq!(table.stats().probes, 1);
+    }
+}
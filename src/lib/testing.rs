@@ -0,0 +1,36 @@
+//! Positions and helpers shared by the unit tests.
+
+use crate::{Board, Game, Piece};
+
+/// The standard game once Black has removed a piece from the centre and White the one beside
+/// it, so that jumping has just begun.
+pub(crate) fn opened() -> Game {
+    let mut game = Game::default();
+    game.remove((2, 2)).unwrap();
+    game.remove((2, 3)).unwrap();
+
+    game
+}
+
+/// The next number from a tiny xorshift generator, so that random positions are the same on
+/// every run.
+pub(crate) fn xorshift(seed: &mut u64) -> u64 {
+    *seed ^= *seed << 13;
+    *seed ^= *seed >> 7;
+    *seed ^= *seed << 17;
+    *seed
+}
+
+/// Fill a board at random, picking each point evenly from `pieces`, row by row.
+pub(crate) fn random_board(seed: &mut u64, width: usize, height: usize, pieces: &[Piece]) -> Board {
+    let mut board = Board::with_size(width, height).unwrap();
+
+    for row in 0..height {
+        for col in 0..width {
+            let piece = pieces[(xorshift(seed) % pieces.len() as u64) as usize];
+            let _ = board.set_piece((row, col), piece);
+        }
+    }
+
+    board
+}